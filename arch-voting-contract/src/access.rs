@@ -0,0 +1,117 @@
+// Role-based access control for the voting contract
+use std::collections::{HashMap, HashSet};
+
+// Roles that can be granted to an address
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Role {
+    Admin,       // Grants and revokes roles
+    PollCreator, // Creates polls
+    Moderator,   // Closes any poll
+    Auditor,     // Reads the full vote record of a poll
+}
+
+// Operations guarded by the authorization layer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Permission {
+    ManageRoles,
+    CreatePoll,
+    CloseAnyPoll,
+    EditAnyPoll,
+    ExportVotes,
+    ManageConfig,
+    ManageCouncil,
+    ProposeActions,
+    ManageCallbacks,
+    ManageBalances,
+    TransferOwnership,
+}
+
+impl Permission {
+    // Roles that are allowed to perform this operation
+    pub fn allowed_roles(&self) -> &'static [Role] {
+        match self {
+            Permission::ManageRoles => &[Role::Admin],
+            Permission::CreatePoll => &[Role::Admin, Role::PollCreator],
+            Permission::CloseAnyPoll => &[Role::Admin, Role::Moderator],
+            Permission::EditAnyPoll => &[Role::Admin],
+            Permission::ExportVotes => &[Role::Admin, Role::Auditor],
+            Permission::ManageConfig => &[Role::Admin],
+            Permission::ManageCouncil => &[Role::Admin],
+            Permission::ProposeActions => &[Role::Admin],
+            Permission::ManageCallbacks => &[Role::Admin],
+            Permission::ManageBalances => &[Role::Admin],
+            // Only the owner, who passes every check
+            Permission::TransferOwnership => &[],
+        }
+    }
+
+    // Whether this operation must go through the council once one exists
+    pub fn requires_council(&self) -> bool {
+        matches!(
+            self,
+            Permission::ManageRoles
+                | Permission::CloseAnyPoll
+                | Permission::EditAnyPoll
+                | Permission::ManageConfig
+                | Permission::ManageCouncil
+                | Permission::ProposeActions
+                | Permission::ManageCallbacks
+                | Permission::ManageBalances
+                | Permission::TransferOwnership
+        )
+    }
+}
+
+// Role membership for all addresses
+#[derive(Debug, Clone, Default)]
+pub struct AccessControl {
+    members: HashMap<Role, HashSet<String>>,
+}
+
+impl AccessControl {
+    // Create an empty role registry
+    pub fn new() -> Self {
+        AccessControl {
+            members: HashMap::new(),
+        }
+    }
+
+    // Check whether an address holds a role
+    pub fn has_role(&self, role: Role, account: &str) -> bool {
+        match self.members.get(&role) {
+            Some(accounts) => accounts.contains(account),
+            None => false,
+        }
+    }
+
+    // Add an address to a role; returns false if it already held it
+    pub fn grant(&mut self, role: Role, account: String) -> bool {
+        self.members.entry(role).or_default().insert(account)
+    }
+
+    // Remove an address from a role; returns false if it did not hold it
+    pub fn revoke(&mut self, role: Role, account: &str) -> bool {
+        match self.members.get_mut(&role) {
+            Some(accounts) => accounts.remove(account),
+            None => false,
+        }
+    }
+
+    // All addresses holding a role, sorted
+    pub fn members(&self, role: Role) -> Vec<String> {
+        let mut accounts: Vec<String> = match self.members.get(&role) {
+            Some(accounts) => accounts.iter().cloned().collect(),
+            None => Vec::new(),
+        };
+        accounts.sort();
+        accounts
+    }
+
+    // Check whether any of an address's roles grants the permission
+    pub fn is_authorized(&self, account: &str, permission: Permission) -> bool {
+        permission
+            .allowed_roles()
+            .iter()
+            .any(|role| self.has_role(*role, account))
+    }
+}
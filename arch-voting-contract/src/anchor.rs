@@ -0,0 +1,112 @@
+// Bitcoin OP_RETURN anchoring for finalized tallies
+//
+// Payload layout (version 1, 76 bytes):
+//
+//   magic        3 bytes   "AVC"
+//   version      1 byte    0x01
+//   poll_id      8 bytes   big-endian
+//   results_hash 32 bytes  SHA-256 over the poll's full tally
+//   state_root   32 bytes  global state root at finalization
+use crate::contract::VotingContract;
+use crate::errors::ContractError;
+use crate::merkle::Hash;
+
+// Prefix identifying our payloads among other OP_RETURN outputs
+pub const MAGIC: [u8; 3] = *b"AVC";
+
+// Current payload format version
+pub const VERSION: u8 = 1;
+
+// Largest payload accepted by standard relay policy
+pub const MAX_PAYLOAD_LEN: usize = 80;
+
+// Length of a version 1 payload
+pub const PAYLOAD_LEN_V1: usize = 3 + 1 + 8 + 32 + 32;
+
+const OP_RETURN: u8 = 0x6a;
+const OP_PUSHDATA1: u8 = 0x4c;
+
+// Compact commitment to a finalized poll's results
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TallyCommitment {
+    pub poll_id: u64,
+    pub results_hash: Hash,
+    pub state_root: Hash,
+}
+
+impl TallyCommitment {
+    // Encode as an OP_RETURN data payload
+    pub fn encode(&self) -> Vec<u8> {
+        let mut payload = Vec::with_capacity(PAYLOAD_LEN_V1);
+        payload.extend_from_slice(&MAGIC);
+        payload.push(VERSION);
+        payload.extend_from_slice(&self.poll_id.to_be_bytes());
+        payload.extend_from_slice(&self.results_hash);
+        payload.extend_from_slice(&self.state_root);
+        payload
+    }
+
+    // Decode a payload produced by `encode`
+    pub fn decode(payload: &[u8]) -> Result<Self, ContractError> {
+        if payload.len() > MAX_PAYLOAD_LEN || payload.len() < MAGIC.len() + 1 {
+            return Err(ContractError::InvalidPayload);
+        }
+
+        if payload[..3] != MAGIC {
+            return Err(ContractError::InvalidPayload);
+        }
+
+        if payload[3] != VERSION || payload.len() != PAYLOAD_LEN_V1 {
+            return Err(ContractError::InvalidPayload);
+        }
+
+        let mut poll_id = [0u8; 8];
+        let mut results_hash = [0u8; 32];
+        let mut state_root = [0u8; 32];
+        poll_id.copy_from_slice(&payload[4..12]);
+        results_hash.copy_from_slice(&payload[12..44]);
+        state_root.copy_from_slice(&payload[44..76]);
+
+        Ok(TallyCommitment {
+            poll_id: u64::from_be_bytes(poll_id),
+            results_hash,
+            state_root,
+        })
+    }
+
+    // Wrap the payload in an OP_RETURN output script
+    pub fn to_script(&self) -> Vec<u8> {
+        let payload = self.encode();
+        let mut script = vec![OP_RETURN];
+
+        // Pushes of up to 75 bytes use the length itself as the opcode
+        if payload.len() > 75 {
+            script.push(OP_PUSHDATA1);
+        }
+        script.push(payload.len() as u8);
+        script.extend_from_slice(&payload);
+        script
+    }
+
+    // Extract the commitment from an OP_RETURN output script
+    pub fn from_script(script: &[u8]) -> Result<Self, ContractError> {
+        let data = match script {
+            [OP_RETURN, OP_PUSHDATA1, len, data @ ..] if *len as usize == data.len() => data,
+            [OP_RETURN, len, data @ ..] if *len <= 75 && *len as usize == data.len() => data,
+            _ => return Err(ContractError::InvalidPayload),
+        };
+
+        Self::decode(data)
+    }
+
+    // Check a decoded commitment against the contract's finalized results
+    pub fn verify(&self, contract: &VotingContract) -> Result<(), ContractError> {
+        let expected = contract.get_tally_commitment(self.poll_id)?;
+
+        if *self != expected {
+            return Err(ContractError::CommitmentMismatch);
+        }
+
+        Ok(())
+    }
+}
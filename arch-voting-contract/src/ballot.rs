@@ -0,0 +1,95 @@
+// Multi-question ballots that share one voting window and voter list. Each
+// question is backed by its own poll, so its votes are indexed, committed and
+// finalized like any other poll's.
+use std::collections::{BTreeSet, HashMap};
+
+use crate::errors::ContractError;
+use crate::models::{PollMethod, VoteChoice};
+
+// How a single question is answered and counted
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VotingMethod {
+    // Pick exactly one option
+    Plurality,
+    // Approve between one and `max_choices` options
+    Approval { max_choices: u32 },
+}
+
+impl VotingMethod {
+    // Method of the poll backing a question
+    pub fn poll_method(&self) -> PollMethod {
+        match self {
+            VotingMethod::Plurality => PollMethod::Plurality,
+            VotingMethod::Approval { max_choices } => PollMethod::Approval { max_choices: *max_choices },
+        }
+    }
+}
+
+// One question on a ballot
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Question {
+    pub prompt: String,
+    pub options: Vec<String>,
+    pub method: VotingMethod,
+}
+
+// A voter's response to one question
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    // Option index for a plurality question
+    Choice(u32),
+    // Option indices for an approval question
+    Approve(Vec<u32>),
+    // Skip the question
+    Abstain,
+}
+
+impl Answer {
+    // Vote this answer casts in a question poll using `method`, or None for
+    // an abstention
+    pub fn to_choice(&self, method: &PollMethod) -> Result<Option<VoteChoice>, ContractError> {
+        match (method, self) {
+            (_, Answer::Abstain) => Ok(None),
+            (PollMethod::Plurality, Answer::Choice(index)) => Ok(Some(VoteChoice::Single(*index))),
+            (PollMethod::Approval { .. }, Answer::Approve(indices)) => Ok(Some(VoteChoice::Approvals(indices.clone()))),
+            _ => Err(ContractError::InvalidOption),
+        }
+    }
+}
+
+// A set of questions voted on together
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ballot {
+    pub id: u64,
+    pub title: String,
+    pub description: String,
+    // IDs of the polls backing each question, in question order
+    pub polls: Vec<u64>,
+    pub creator: String,
+    // Unix timestamp when voting starts
+    pub start_time: u64,
+    // Unix timestamp when voting ends
+    pub end_time: u64,
+    pub active: bool,
+    // Wallets allowed to vote; anyone may vote when unset
+    pub eligible_voters: Option<BTreeSet<String>>,
+}
+
+// Tally for one question
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QuestionResults {
+    // Mapping of option_index to votes (approvals for approval questions)
+    pub counts: HashMap<u32, u64>,
+    // Voters who answered this question
+    pub turnout: u64,
+    // Voters who submitted the ballot but skipped this question
+    pub abstentions: u64,
+}
+
+// Tally for a whole ballot
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BallotResults {
+    // Voters who submitted the ballot
+    pub turnout: u64,
+    pub questions: Vec<QuestionResults>,
+}
@@ -0,0 +1,25 @@
+// Source of the current time for the contract
+use std::time::{SystemTime, UNIX_EPOCH};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Clock {
+    // Current Unix timestamp (in a real deployment this would be the block time)
+    #[default]
+    System,
+    // Fixed Unix timestamp that only moves when the host sets it. For tests
+    // and simulations only: `set_time` has no caller check, so a deployed
+    // contract must never use it.
+    Manual(u64),
+}
+
+impl Clock {
+    pub fn now(&self) -> u64 {
+        match self {
+            Clock::System => SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap()
+                .as_secs(),
+            Clock::Manual(timestamp) => *timestamp,
+        }
+    }
+}
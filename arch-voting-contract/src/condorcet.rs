@@ -0,0 +1,243 @@
+// Condorcet tallies (Schulze and Ranked Pairs) over a pairwise preference matrix
+
+// (winner, loser) option pairs
+type Pairs = Vec<(u32, u32)>;
+
+// Which Condorcet method decides the winner of a ranked poll
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CondorcetRule {
+    #[default]
+    Schulze,
+    // Tideman's Ranked Pairs
+    RankedPairs,
+}
+
+// Full audit view of a ranked poll's Condorcet tallies
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CondorcetResults {
+    pub poll_id: u64,
+    // matrix[i][j]: voters who ranked option i above option j
+    pub matrix: Vec<Vec<u64>>,
+    // strongest_paths[i][j]: strength of the strongest Schulze path from i to j
+    pub strongest_paths: Vec<Vec<u64>>,
+    // Option that beats every other option head-to-head, if any
+    pub condorcet_winner: Option<u32>,
+    // Groups of options that beat each other in a cycle, each sorted, ordered
+    // by their lowest index
+    pub cycles: Vec<Vec<u32>>,
+    // Options not beaten by any other Schulze path; more than one means a tie
+    pub schulze_winners: Vec<u32>,
+    // All options in Schulze order, ties in index order
+    pub schulze_ranking: Vec<u32>,
+    // Sources of the locked Ranked Pairs graph; more than one means a tie
+    pub ranked_pairs_winners: Vec<u32>,
+    // All options in Ranked Pairs order, ties in index order
+    pub ranked_pairs_ranking: Vec<u32>,
+    // Majorities locked in, as (winner, loser), strongest first
+    pub locked_pairs: Vec<(u32, u32)>,
+    // Majorities skipped because they would have closed a cycle
+    pub skipped_pairs: Vec<(u32, u32)>,
+}
+
+impl CondorcetResults {
+    // Run both methods over a pairwise matrix
+    pub fn new(poll_id: u64, matrix: &[Vec<u64>]) -> Self {
+        let n = matrix.len();
+
+        let condorcet_winner = (0..n)
+            .find(|i| (0..n).all(|j| *i == j || matrix[*i][j] > matrix[j][*i]))
+            .map(|i| i as u32);
+
+        let strongest_paths = strongest_paths(matrix);
+        let beats = |i: usize, j: usize| strongest_paths[i][j] > strongest_paths[j][i];
+        let schulze_winners = (0..n)
+            .filter(|i| (0..n).all(|j| !beats(j, *i)))
+            .map(|i| i as u32)
+            .collect();
+        // Schulze's relation is transitive, so counting wins orders it
+        let mut schulze_ranking: Vec<u32> = (0..n as u32).collect();
+        schulze_ranking.sort_by_key(|i| {
+            std::cmp::Reverse((0..n).filter(|j| beats(*i as usize, *j)).count())
+        });
+
+        let (locked_pairs, skipped_pairs) = ranked_pairs(matrix);
+        let (ranked_pairs_winners, ranked_pairs_ranking) = order_locked(n, &locked_pairs);
+
+        CondorcetResults {
+            poll_id,
+            matrix: matrix.to_vec(),
+            strongest_paths,
+            condorcet_winner,
+            cycles: cycles(matrix),
+            schulze_winners,
+            schulze_ranking,
+            ranked_pairs_winners,
+            ranked_pairs_ranking,
+            locked_pairs,
+            skipped_pairs,
+        }
+    }
+
+    // Winners under the given rule; more than one means a tie
+    pub fn winners(&self, rule: CondorcetRule) -> &[u32] {
+        match rule {
+            CondorcetRule::Schulze => &self.schulze_winners,
+            CondorcetRule::RankedPairs => &self.ranked_pairs_winners,
+        }
+    }
+}
+
+// Add one ranked ballot to a pairwise matrix. Ranked options beat every
+// option ranked below them and every unranked option.
+pub fn record_ranking(matrix: &mut [Vec<u64>], ranking: &[u32]) {
+    let mut ranked = vec![false; matrix.len()];
+
+    for winner in ranking {
+        let winner = *winner as usize;
+        ranked[winner] = true;
+        for (loser, is_ranked) in ranked.iter().enumerate() {
+            if !is_ranked {
+                matrix[winner][loser] += 1;
+            }
+        }
+    }
+}
+
+// Widest paths through the graph of pairwise majorities (Floyd-Warshall)
+fn strongest_paths(matrix: &[Vec<u64>]) -> Vec<Vec<u64>> {
+    let n = matrix.len();
+    let mut paths = vec![vec![0u64; n]; n];
+
+    for i in 0..n {
+        for j in 0..n {
+            if i != j && matrix[i][j] > matrix[j][i] {
+                paths[i][j] = matrix[i][j];
+            }
+        }
+    }
+
+    for k in 0..n {
+        for i in 0..n {
+            if i == k {
+                continue;
+            }
+            for j in 0..n {
+                if j != i && j != k {
+                    paths[i][j] = paths[i][j].max(paths[i][k].min(paths[k][j]));
+                }
+            }
+        }
+    }
+
+    paths
+}
+
+// Lock majorities from strongest to weakest, skipping any that would close a
+// cycle. Majorities are ordered by winning votes, then by fewest opposing
+// votes, then by index so the result is deterministic.
+fn ranked_pairs(matrix: &[Vec<u64>]) -> (Pairs, Pairs) {
+    let n = matrix.len();
+    let mut majorities: Vec<(usize, usize)> = (0..n)
+        .flat_map(|i| (0..n).map(move |j| (i, j)))
+        .filter(|(i, j)| matrix[*i][*j] > matrix[*j][*i])
+        .collect();
+    majorities.sort_by_key(|(i, j)| (std::cmp::Reverse(matrix[*i][*j]), matrix[*j][*i], *i, *j));
+
+    let mut graph = vec![vec![false; n]; n];
+    let mut locked = Vec::new();
+    let mut skipped = Vec::new();
+
+    for (winner, loser) in majorities {
+        if reaches(&graph, loser, winner) {
+            skipped.push((winner as u32, loser as u32));
+        } else {
+            graph[winner][loser] = true;
+            locked.push((winner as u32, loser as u32));
+        }
+    }
+
+    (locked, skipped)
+}
+
+// Whether `to` can be reached from `from` along locked edges
+fn reaches(graph: &[Vec<bool>], from: usize, to: usize) -> bool {
+    let mut seen = vec![false; graph.len()];
+    let mut stack = vec![from];
+
+    while let Some(node) = stack.pop() {
+        if node == to {
+            return true;
+        }
+        if seen[node] {
+            continue;
+        }
+        seen[node] = true;
+        stack.extend((0..graph.len()).filter(|next| graph[node][*next] && !seen[*next]));
+    }
+
+    false
+}
+
+// Sources of the locked graph, and a full order that repeatedly removes the
+// current sources in index order
+fn order_locked(n: usize, locked: &[(u32, u32)]) -> (Vec<u32>, Vec<u32>) {
+    let mut remaining: Vec<u32> = (0..n as u32).collect();
+    let mut ranking = Vec::with_capacity(n);
+    let mut winners = Vec::new();
+
+    while !remaining.is_empty() {
+        let sources: Vec<u32> = remaining
+            .iter()
+            .copied()
+            .filter(|option| {
+                !locked
+                    .iter()
+                    .any(|(winner, loser)| loser == option && remaining.contains(winner))
+            })
+            .collect();
+
+        if winners.is_empty() {
+            winners = sources.clone();
+        }
+        remaining.retain(|option| !sources.contains(option));
+        ranking.extend(sources);
+    }
+
+    (winners, ranking)
+}
+
+// Strongly connected groups of the majority graph with more than one member
+fn cycles(matrix: &[Vec<u64>]) -> Vec<Vec<u32>> {
+    let n = matrix.len();
+    let mut reach: Vec<Vec<bool>> = (0..n)
+        .map(|i| (0..n).map(|j| matrix[i][j] > matrix[j][i]).collect())
+        .collect();
+
+    for k in 0..n {
+        for i in 0..n {
+            for j in 0..n {
+                if reach[i][k] && reach[k][j] {
+                    reach[i][j] = true;
+                }
+            }
+        }
+    }
+
+    let mut assigned = vec![false; n];
+    let mut groups = Vec::new();
+    for i in 0..n {
+        if assigned[i] || !reach[i][i] {
+            continue;
+        }
+        let group: Vec<u32> = (i..n)
+            .filter(|j| *j == i || (reach[i][*j] && reach[*j][i]))
+            .map(|j| j as u32)
+            .collect();
+        for j in &group {
+            assigned[*j as usize] = true;
+        }
+        groups.push(group);
+    }
+
+    groups
+}
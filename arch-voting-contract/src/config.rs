@@ -0,0 +1,96 @@
+// Contract-wide configuration parameters
+use std::collections::BTreeSet;
+
+use crate::errors::ContractError;
+use crate::results::BASIS_POINTS;
+
+// Shortest voting window an executable proposal may be given, in seconds
+pub const MIN_PROPOSAL_DURATION: u64 = 86400;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContractConfig {
+    // Largest number of options a poll may have
+    pub max_options: u32,
+    // Seconds a council proposal stays open for approval
+    pub approval_window: u64,
+    // Longest vote-escrow lock in seconds; a lock this long earns full power
+    pub max_lock_duration: u64,
+    // Seconds an executable proposal's poll stays open
+    pub proposal_duration: u64,
+    // Share of the electorate that must vote for a proposal to pass, in
+    // basis points
+    pub proposal_quorum_bps: u32,
+    // Wallets that vote on executable proposals; none can be created while
+    // it is empty
+    pub proposal_electorate: BTreeSet<String>,
+}
+
+impl Default for ContractConfig {
+    fn default() -> Self {
+        ContractConfig {
+            max_options: 64,
+            approval_window: 7 * 86400,
+            max_lock_duration: 4 * 365 * 86400,
+            proposal_duration: 3 * 86400,
+            proposal_quorum_bps: 5000,
+            proposal_electorate: BTreeSet::new(),
+        }
+    }
+}
+
+// A single configuration change
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfigParam {
+    MaxOptions(u32),
+    ApprovalWindow(u64),
+    MaxLockDuration(u64),
+    ProposalDuration(u64),
+    ProposalQuorum(u32),
+    ProposalElectorate(BTreeSet<String>),
+}
+
+impl ContractConfig {
+    // Validate and apply a configuration change
+    pub fn apply(&mut self, param: &ConfigParam) -> Result<(), ContractError> {
+        match param {
+            ConfigParam::MaxOptions(max) => {
+                if *max < 2 {
+                    return Err(ContractError::InvalidConfig);
+                }
+                self.max_options = *max;
+            }
+            ConfigParam::ApprovalWindow(window) => {
+                if *window == 0 {
+                    return Err(ContractError::InvalidConfig);
+                }
+                self.approval_window = *window;
+            }
+            ConfigParam::MaxLockDuration(duration) => {
+                if *duration == 0 {
+                    return Err(ContractError::InvalidConfig);
+                }
+                self.max_lock_duration = *duration;
+            }
+            ConfigParam::ProposalDuration(duration) => {
+                if *duration < MIN_PROPOSAL_DURATION {
+                    return Err(ContractError::InvalidConfig);
+                }
+                self.proposal_duration = *duration;
+            }
+            ConfigParam::ProposalQuorum(quorum_bps) => {
+                if *quorum_bps == 0 || *quorum_bps > BASIS_POINTS {
+                    return Err(ContractError::InvalidConfig);
+                }
+                self.proposal_quorum_bps = *quorum_bps;
+            }
+            ConfigParam::ProposalElectorate(electorate) => {
+                if electorate.iter().any(|wallet| wallet.is_empty()) {
+                    return Err(ContractError::InvalidConfig);
+                }
+                self.proposal_electorate = electorate.clone();
+            }
+        }
+
+        Ok(())
+    }
+}
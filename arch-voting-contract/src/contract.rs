@@ -1,89 +1,431 @@
 // Main contract implementation
-use std::collections::HashMap;
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::cell::RefCell;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::ops::Bound;
 
+use crate::access::{AccessControl, Permission, Role};
+use crate::anchor::TallyCommitment;
+use crate::ballot::{Answer, Ballot, BallotResults, Question, QuestionResults};
+use crate::clock::Clock;
+use crate::condorcet::CondorcetResults;
+use crate::conviction::{ConvictionConfig, ConvictionPool, ConvictionStatus, FundingProposal};
+use crate::judgment::MajorityJudgmentResults;
+use crate::positional::{PositionalResults, PositionalRule};
+use crate::star::StarResults;
+use crate::stv::StvResults;
+use crate::config::{ConfigParam, ContractConfig};
+use crate::council::{AdminAction, AdminProposal, Council};
 use crate::errors::ContractError;
-use crate::models::{Poll, VoteResults};
+use crate::escrow::{Lock, LockHistory};
+use crate::events::Event;
+use crate::governance::{self, Callback, ExecutableProposal};
+use crate::merkle::{self, FinalizedState, Hash, MerkleProof, MerkleTree, VoteInclusionProof};
+use crate::models::{
+    self, Cancellation, Poll, PollEdit, PollMethod, PollOption, PollSettings, PollUpdate, TieBreakPolicy,
+    VoteChoice, VoteResults, VotingPower,
+};
+use crate::results::{self, OrderedResults, PollOutcome, RoundingMode, BASIS_POINTS};
+use crate::query::{
+    self, PageRequest, PollCursor, PollPage, PollQuery, PollSortKey, WalletVote, WalletVotesPage,
+};
 
 // Main contract struct that holds all state
 pub struct VotingContract {
     // Mapping of poll_id to Poll struct
     polls: HashMap<u64, Poll>,
-    // Mapping of poll_id to a map of wallet_address to vote_option
-    votes: HashMap<u64, HashMap<String, u32>>,
+    // Mapping of poll_id to a map of wallet_address to ballot
+    votes: HashMap<u64, HashMap<String, VoteChoice>>,
+    // Mapping of wallet_address to the polls it voted in (poll_id to ballot)
+    wallet_votes: HashMap<String, BTreeMap<u64, VoteChoice>>,
     // Mapping of poll_id to VoteResults
     results: HashMap<u64, VoteResults>,
+    // Mapping of poll_id to the root over its votes, dropped when a vote is
+    // recorded and rebuilt on the next lookup
+    vote_roots: RefCell<HashMap<u64, Hash>>,
+    // Mapping of poll_id to its place in the state tree when it was finalized
+    finalized_states: HashMap<u64, FinalizedState>,
     // Poll counter for generating unique poll IDs
     poll_counter: u64,
-    // Contract owner address
+    // Mapping of ballot_id to multi-question Ballot
+    ballots: HashMap<u64, Ballot>,
+    // Mapping of ballot_id to the wallets that submitted it
+    ballot_voters: HashMap<u64, BTreeSet<String>>,
+    // Ballot counter for generating unique ballot IDs
+    ballot_counter: u64,
+    // Mapping of pool_id to long-running conviction pool
+    conviction_pools: HashMap<u64, ConvictionPool>,
+    // Counter for generating unique conviction pool IDs
+    conviction_pool_counter: u64,
+    // Mapping of wallet_address to the history of its vote-escrow lock
+    locks: HashMap<String, LockHistory>,
+    // Mapping of wallet_address to the balance it may lock, set by admins
+    balances: HashMap<String, u64>,
+    // Mapping of poll_id to the actions it runs if it passes
+    executable_proposals: HashMap<u64, ExecutableProposal>,
+    // Callbacks that passed proposals can call, by name
+    callbacks: HashMap<String, Callback>,
+    // Callbacks registered while a council exists, awaiting its approval
+    staged_callbacks: HashMap<String, Callback>,
+    // Contract owner address (implicitly holds the Admin role)
     owner: String,
+    // Role assignments checked by `authorize`
+    access: AccessControl,
+    // Address nominated to become owner, awaiting acceptance
+    pending_owner: Option<String>,
+    // Events emitted so far, oldest first
+    events: Vec<Event>,
+    // Contract-wide configuration
+    config: ContractConfig,
+    // Admin council; once set, privileged actions need its approval
+    council: Option<Council>,
+    // Mapping of proposal_id to council proposal
+    admin_proposals: HashMap<u64, AdminProposal>,
+    // Counter for generating unique council proposal IDs
+    admin_proposal_counter: u64,
+    // Where the current time comes from
+    clock: Clock,
 }
 
 impl VotingContract {
     // Initialize a new voting contract
     pub fn new(owner: String) -> Self {
+        Self::with_clock(owner, Clock::System)
+    }
+
+    // Initialize a new voting contract that reads time from `clock`
+    pub fn with_clock(owner: String, clock: Clock) -> Self {
         VotingContract {
             polls: HashMap::new(),
             votes: HashMap::new(),
+            wallet_votes: HashMap::new(),
             results: HashMap::new(),
+            vote_roots: RefCell::new(HashMap::new()),
+            finalized_states: HashMap::new(),
             poll_counter: 0,
+            ballots: HashMap::new(),
+            ballot_voters: HashMap::new(),
+            ballot_counter: 0,
+            conviction_pools: HashMap::new(),
+            conviction_pool_counter: 0,
+            locks: HashMap::new(),
+            balances: HashMap::new(),
+            executable_proposals: HashMap::new(),
+            callbacks: HashMap::new(),
+            staged_callbacks: HashMap::new(),
             owner,
+            access: AccessControl::new(),
+            pending_owner: None,
+            events: Vec::new(),
+            config: ContractConfig::default(),
+            council: None,
+            admin_proposals: HashMap::new(),
+            admin_proposal_counter: 0,
+            clock,
+        }
+    }
+
+    // Move a manual clock to `timestamp`; it can never run backwards. Anyone
+    // may call this, which is why a manual clock is for tests and
+    // simulations only.
+    pub fn set_time(&mut self, timestamp: u64) -> Result<(), ContractError> {
+        match self.clock {
+            Clock::Manual(now) if timestamp >= now => {
+                self.clock = Clock::Manual(timestamp);
+                Ok(())
+            }
+            Clock::Manual(_) => Err(ContractError::InvalidTimeRange),
+            Clock::System => Err(ContractError::SystemClock),
         }
     }
 
-    // Create a new poll
+    // Create a new poll with default settings
     pub fn create_poll(
         &mut self,
         creator: String, 
         title: String, 
         description: String, 
-        options: Vec<String>, 
+        options: Vec<impl Into<PollOption>>, 
         start_time: u64, 
         end_time: u64
     ) -> Result<u64, ContractError> {
+        self.create_poll_with_settings(
+            creator,
+            title,
+            description,
+            options,
+            start_time,
+            end_time,
+            PollSettings::default(),
+        )
+    }
+    
+    // Create a new poll with explicit per-poll settings
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_poll_with_settings(
+        &mut self,
+        creator: String,
+        title: String,
+        description: String,
+        options: Vec<impl Into<PollOption>>,
+        start_time: u64,
+        end_time: u64,
+        settings: PollSettings,
+    ) -> Result<u64, ContractError> {
+        self.authorize(&creator, Permission::CreatePoll)?;
+        
+        let options: Vec<PollOption> = options.into_iter().map(Into::into).collect();
+        
         // Validate inputs
-        if options.len() < 2 {
-            return Err(ContractError::InvalidOption);
-        }
+        self.validate_options(&options)?;
         
         if start_time >= end_time {
             return Err(ContractError::InvalidTimeRange);
         }
         
-        // Generate a new unique poll ID
-        let poll_id = self.poll_counter;
-        self.poll_counter += 1;
+        if settings.tie_break == (TieBreakPolicy::Runoff { duration: 0 }) {
+            return Err(ContractError::InvalidTimeRange);
+        }
+        
+        validate_method(&settings.method, options.len())?;
+        
+        // A majority runoff compares the leader's single votes against all
+        // votes cast, which only means something for plurality ballots; the
+        // other methods sum points, rank options or run their own runoff
+        if settings.method != PollMethod::Plurality && settings.runoff.is_some()
+        {
+            return Err(ContractError::InvalidSettings);
+        }
+        
+        // Only single-choice ballots carry a weight
+        if settings.voting_power == VotingPower::Escrow && settings.method != PollMethod::Plurality {
+            return Err(ContractError::InvalidSettings);
+        }
+        
+        if let Some(runoff) = settings.runoff {
+            if runoff.duration == 0 {
+                return Err(ContractError::InvalidTimeRange);
+            }
+            if runoff.top_n < 2 || runoff.majority_bps == 0 || runoff.majority_bps > BASIS_POINTS {
+                return Err(ContractError::InvalidSettings);
+            }
+        }
         
-        // Create the poll
         let poll = Poll {
-            id: poll_id,
+            id: 0,
             title,
             description,
-            options: options.clone(),
+            options,
             creator,
             start_time,
             end_time,
             active: true,
+            finalized: false,
+            tags: Vec::new(),
+            settings,
+            parent_poll: None,
+            runoff_poll: None,
+            edit_history: Vec::new(),
+            cancellation: None,
+            draw_seed: None,
+            ballot: None,
         };
         
-        // Initialize vote tracking for this poll
-        self.polls.insert(poll_id, poll);
-        self.votes.insert(poll_id, HashMap::new());
+        Ok(self.insert_poll(poll))
+    }
+    
+    // Check an option list against the configured count and size limits
+    fn validate_options(&self, options: &[PollOption]) -> Result<(), ContractError> {
+        if options.len() < 2 || options.len() > self.config.max_options as usize {
+            return Err(ContractError::InvalidOption);
+        }
+        
+        if options.iter().any(|option| option.label.trim().is_empty()) {
+            return Err(ContractError::EmptyLabel);
+        }
+        
+        if !options.iter().all(PollOption::validate) {
+            return Err(ContractError::OptionTooLarge);
+        }
+        
+        // Labels must stay distinct once normalized
+        let mut labels: Vec<String> = options.iter().map(|o| models::normalize_label(&o.label)).collect();
+        labels.sort();
+        labels.dedup();
+        if labels.len() != options.len() {
+            return Err(ContractError::DuplicateOption);
+        }
+        
+        Ok(())
+    }
+    
+    // Edit a poll's details before voting starts (its creator or an admin).
+    // The replaced values are appended to the poll's edit history.
+    pub fn update_poll(&mut self, poll_id: u64, update: PollUpdate, caller: String) -> Result<(), ContractError> {
+        let poll = match self.polls.get(&poll_id) {
+            Some(p) => p,
+            None => return Err(ContractError::PollNotFound),
+        };
+        
+        if poll.creator != caller {
+            self.authorize(&caller, Permission::EditAnyPoll)?;
+        }
+        
+        // Questions share their ballot's window and options
+        if poll.ballot.is_some() {
+            return Err(ContractError::BallotQuestion);
+        }
+        
+        if !poll.active {
+            return Err(ContractError::PollNotActive);
+        }
+        
+        // Votes can only be cast once voting starts, so none have been yet
+        let now = self.clock.now();
+        if now >= poll.start_time {
+            return Err(ContractError::PollAlreadyStarted);
+        }
+        
+        if let Some(options) = &update.options {
+            self.validate_options(options)?;
+            validate_method(&poll.settings.method, options.len())?;
+        }
+        
+        let start_time = update.start_time.unwrap_or(poll.start_time);
+        let end_time = update.end_time.unwrap_or(poll.end_time);
+        if start_time >= end_time {
+            return Err(ContractError::InvalidTimeRange);
+        }
+        
+        if update == PollUpdate::default() {
+            return Ok(());
+        }
+        
+        let poll = self.polls.get_mut(&poll_id).unwrap();
+        let previous = PollUpdate {
+            title: update.title.map(|title| std::mem::replace(&mut poll.title, title)),
+            description: update.description.map(|description| std::mem::replace(&mut poll.description, description)),
+            options: update.options.map(|options| std::mem::replace(&mut poll.options, options)),
+            start_time: update.start_time.map(|time| std::mem::replace(&mut poll.start_time, time)),
+            end_time: update.end_time.map(|time| std::mem::replace(&mut poll.end_time, time)),
+        };
+        poll.edit_history.push(PollEdit {
+            editor: caller.clone(),
+            edited_at: now,
+            previous,
+        });
+        
+        // The option list may have changed size
+        self.results.insert(poll_id, VoteResults::for_poll(poll));
+        
+        self.events.push(Event::PollUpdated {
+            poll_id,
+            editor: caller,
+        });
+        
+        Ok(())
+    }
+    
+    // Assign an ID to a validated poll and set up its vote tracking
+    fn insert_poll(&mut self, mut poll: Poll) -> u64 {
+        // Generate a new unique poll ID
+        let poll_id = self.poll_counter;
+        self.poll_counter += 1;
+        poll.id = poll_id;
         
         // Initialize results for this poll
-        let results = VoteResults::new(options.len());
+        let results = VoteResults::for_poll(&poll);
         self.results.insert(poll_id, results);
         
-        Ok(poll_id)
+        // Initialize vote tracking for this poll
+        self.polls.insert(poll_id, poll);
+        self.votes.insert(poll_id, HashMap::new());
+        
+        poll_id
     }
     
-    // Cast a vote in a poll
+    // Cast a vote in a plurality poll
     pub fn vote(
         &mut self, 
         poll_id: u64, 
         wallet_address: String, 
         option_index: u32
     ) -> Result<(), ContractError> {
+        self.cast_vote(poll_id, wallet_address, VoteChoice::Single(option_index))
+    }
+    
+    // Score every option of a score or STAR poll; None leaves an option unscored
+    pub fn vote_scores(
+        &mut self,
+        poll_id: u64,
+        wallet_address: String,
+        scores: Vec<Option<u32>>,
+    ) -> Result<(), ContractError> {
+        self.cast_vote(poll_id, wallet_address, VoteChoice::Scores(scores))
+    }
+    
+    // Rank options from most to least preferred in a ranked poll
+    pub fn vote_ranked(
+        &mut self,
+        poll_id: u64,
+        wallet_address: String,
+        ranking: Vec<u32>,
+    ) -> Result<(), ContractError> {
+        self.cast_vote(poll_id, wallet_address, VoteChoice::Ranking(ranking))
+    }
+    
+    // Spread points across the options of a cumulative poll
+    pub fn vote_points(
+        &mut self,
+        poll_id: u64,
+        wallet_address: String,
+        points: Vec<u64>,
+    ) -> Result<(), ContractError> {
+        self.cast_vote(poll_id, wallet_address, VoteChoice::Points(points))
+    }
+    
+    // Grade every option of a majority judgment poll
+    pub fn vote_grades(
+        &mut self,
+        poll_id: u64,
+        wallet_address: String,
+        grades: Vec<u32>,
+    ) -> Result<(), ContractError> {
+        self.cast_vote(poll_id, wallet_address, VoteChoice::Grades(grades))
+    }
+    
+    // Approve one or more options of an approval poll
+    pub fn vote_approvals(
+        &mut self,
+        poll_id: u64,
+        wallet_address: String,
+        approvals: Vec<u32>,
+    ) -> Result<(), ContractError> {
+        self.cast_vote(poll_id, wallet_address, VoteChoice::Approvals(approvals))
+    }
+    
+    // Validate and record a ballot of any form
+    fn cast_vote(
+        &mut self,
+        poll_id: u64,
+        wallet_address: String,
+        choice: VoteChoice,
+    ) -> Result<(), ContractError> {
+        // Questions are answered together through `cast_ballot`
+        if self.get_poll(poll_id)?.ballot.is_some() {
+            return Err(ContractError::BallotQuestion);
+        }
+        
+        let weight = self.check_vote(poll_id, &wallet_address, &choice)?;
+        self.record_vote(poll_id, wallet_address, choice, weight)
+    }
+    
+    // Check a ballot can be cast in the poll and return the weight it counts for
+    fn check_vote(
+        &self,
+        poll_id: u64,
+        wallet_address: &str,
+        choice: &VoteChoice,
+    ) -> Result<u64, ContractError> {
         // Check if poll exists
         let poll = match self.polls.get(&poll_id) {
             Some(p) => p,
@@ -96,38 +438,61 @@ impl VotingContract {
         }
         
         // Check if voting period is valid
-        let current_time = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap()
-            .as_secs();
+        let now = self.clock.now();
             
-        if current_time < poll.start_time {
+        if now < poll.start_time {
             return Err(ContractError::PollNotActive);
         }
         
-        if current_time > poll.end_time {
+        if now > poll.end_time {
             return Err(ContractError::PollAlreadyEnded);
         }
         
-        // Check if option is valid
-        if option_index as usize >= poll.options.len() {
-            return Err(ContractError::InvalidOption);
+        // Check if the wallet may vote in this poll
+        if let Some(eligible) = &poll.settings.eligible_voters {
+            if !eligible.contains(wallet_address) {
+                return Err(ContractError::NotEligible);
+            }
+        }
+        
+        // Check the ballot fits the poll
+        poll.validate_choice(wallet_address, choice)?;
+        
+        // Weigh the vote by the wallet's locked balance at the snapshot
+        let weight = match poll.settings.voting_power {
+            VotingPower::OnePerWallet => 1,
+            VotingPower::Escrow => self.get_voting_power(wallet_address, poll.start_time),
+        };
+        if weight == 0 {
+            return Err(ContractError::NoVotingPower);
         }
         
         // Check if user has already voted
-        let poll_votes = self.votes.get_mut(&poll_id).unwrap();
-        if poll_votes.contains_key(&wallet_address) {
+        if self.votes[&poll_id].contains_key(wallet_address) {
             return Err(ContractError::AlreadyVoted);
         }
         
-        // Record the vote
-        poll_votes.insert(wallet_address, option_index);
-        
+        Ok(weight)
+    }
+    
+    // Count and store a ballot that has passed `check_vote`
+    fn record_vote(
+        &mut self,
+        poll_id: u64,
+        wallet_address: String,
+        choice: VoteChoice,
+        weight: u64,
+    ) -> Result<(), ContractError> {
         // Update the results
-        let results = self.results.get_mut(&poll_id).unwrap();
-        let count = results.counts.get_mut(&option_index).unwrap();
-        *count += 1;
-        results.total_votes += 1;
+        self.results.get_mut(&poll_id).unwrap().record_weighted(&choice, weight)?;
+        
+        // Record the vote
+        self.votes.get_mut(&poll_id).unwrap().insert(wallet_address.clone(), choice.clone());
+        self.vote_roots.get_mut().remove(&poll_id);
+        self.wallet_votes
+            .entry(wallet_address)
+            .or_default()
+            .insert(poll_id, choice);
         
         Ok(())
     }
@@ -148,38 +513,93 @@ impl VotingContract {
         }
     }
     
-    // Close a poll (only creator or owner can do this)
+    // Close a poll (only its creator, a moderator or an admin can do this)
     pub fn close_poll(&mut self, poll_id: u64, caller: String) -> Result<(), ContractError> {
-        let poll = match self.polls.get_mut(&poll_id) {
-            Some(p) => p,
+        let creator = match self.polls.get(&poll_id) {
+            Some(p) => &p.creator,
             None => return Err(ContractError::PollNotFound),
         };
         
         // Check if caller is authorized
-        if poll.creator != caller && self.owner != caller {
-            return Err(ContractError::Unauthorized);
+        if *creator != caller {
+            self.authorize(&caller, Permission::CloseAnyPoll)?;
         }
         
-        poll.active = false;
+        // Questions share their ballot's window and close with it
+        let poll = &self.polls[&poll_id];
+        if poll.ballot.is_some() {
+            return Err(ContractError::BallotQuestion);
+        }
+        
+        self.check_proposal_ended(poll)?;
+        self.polls.get_mut(&poll_id).unwrap().active = false;
+        
+        Ok(())
+    }
+    
+    // Proposals run for their full window, so nobody can cut a vote short
+    // by closing or cancelling its poll
+    fn check_proposal_ended(&self, poll: &Poll) -> Result<(), ContractError> {
+        if self.executable_proposals.contains_key(&poll.id) && self.clock.now() <= poll.end_time {
+            return Err(ContractError::PollNotEnded);
+        }
+        
+        Ok(())
+    }
+    
+    // Void a poll, recording why. Unlike closing, a cancelled poll has no
+    // valid outcome and cannot be finalized.
+    pub fn cancel_poll(&mut self, poll_id: u64, caller: String, reason: String) -> Result<(), ContractError> {
+        let poll = match self.polls.get(&poll_id) {
+            Some(p) => p,
+            None => return Err(ContractError::PollNotFound),
+        };
+        
+        if poll.creator != caller {
+            self.authorize(&caller, Permission::CloseAnyPoll)?;
+        }
+        
+        // Questions are cancelled with their whole ballot
+        if poll.ballot.is_some() {
+            return Err(ContractError::BallotQuestion);
+        }
+        
+        self.check_proposal_ended(poll)?;
+        check_cancellation(poll, &reason)?;
+        self.void_poll(poll_id, &caller, &reason);
         
         Ok(())
     }
     
+    // Mark a poll cancelled by `caller` and emit the event
+    fn void_poll(&mut self, poll_id: u64, caller: &str, reason: &str) {
+        let poll = self.polls.get_mut(&poll_id).unwrap();
+        poll.active = false;
+        poll.cancellation = Some(Cancellation {
+            reason: reason.to_string(),
+            cancelled_by: caller.to_string(),
+            cancelled_at: self.clock.now(),
+        });
+        
+        self.events.push(Event::PollCancelled {
+            poll_id,
+            cancelled_by: caller.to_string(),
+            reason: reason.to_string(),
+        });
+    }
+    
     // Auto-close polls that have reached their end time
     pub fn process_expired_polls(&mut self) {
-        let current_time = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap()
-            .as_secs();
+        let now = self.clock.now();
             
         for (_, poll) in self.polls.iter_mut() {
-            if poll.active && current_time > poll.end_time {
+            if poll.active && now > poll.end_time {
                 poll.active = false;
             }
         }
     }
     
-    // Get all active polls
+    // Get all active polls, ordered by ID
     pub fn get_active_polls(&self) -> Vec<u64> {
         let mut active_polls = Vec::new();
         
@@ -189,28 +609,134 @@ impl VotingContract {
             }
         }
         
+        active_polls.sort();
         active_polls
     }
     
-    // Get detailed vote results with percentage
+    // Get the polls a wallet voted in, ordered by poll ID
+    pub fn get_votes_by_wallet(&self, wallet_address: &str, page: &PageRequest) -> WalletVotesPage {
+        let history = match self.wallet_votes.get(wallet_address) {
+            Some(h) => h,
+            None => {
+                return WalletVotesPage {
+                    votes: Vec::new(),
+                    next_cursor: None,
+                }
+            }
+        };
+        
+        let limit = page.limit.clamp(1, query::MAX_PAGE_SIZE);
+        let start = match page.after {
+            Some(after) => Bound::Excluded(after),
+            None => Bound::Unbounded,
+        };
+        let mut remaining = history
+            .range((start, Bound::Unbounded))
+            .map(|(poll_id, choice)| WalletVote {
+                poll_id: *poll_id,
+                choice: choice.clone(),
+            });
+        
+        let votes: Vec<WalletVote> = remaining.by_ref().take(limit).collect();
+        let next_cursor = match remaining.next() {
+            Some(_) => votes.last().map(|vote| vote.poll_id),
+            None => None,
+        };
+        
+        WalletVotesPage { votes, next_cursor }
+    }
+    
+    // Replace a poll's tags (creator only)
+    pub fn set_poll_tags(&mut self, poll_id: u64, tags: Vec<String>, caller: String) -> Result<(), ContractError> {
+        let poll = match self.polls.get_mut(&poll_id) {
+            Some(p) => p,
+            None => return Err(ContractError::PollNotFound),
+        };
+        
+        if poll.creator != caller {
+            return Err(ContractError::Unauthorized);
+        }
+        
+        let mut tags = tags;
+        tags.sort();
+        tags.dedup();
+        poll.tags = tags;
+        
+        Ok(())
+    }
+    
+    // Find polls matching the query, one page at a time. Results are ordered
+    // by the sort key and then by poll ID, so paging is stable across calls.
+    pub fn query_polls(&self, query: &PollQuery) -> PollPage {
+        let now = self.clock.now();
+        let title_filter = query.title_contains.as_ref().map(|t| t.to_lowercase());
+        
+        let entries = self
+            .polls
+            .values()
+            .filter(|poll| match &query.creator {
+                Some(creator) => poll.creator == *creator,
+                None => true,
+            })
+            .filter(|poll| match query.status {
+                Some(status) => poll.status(now) == status,
+                None => true,
+            })
+            .filter(|poll| match query.window {
+                Some((from, to)) => poll.start_time <= to && poll.end_time >= from,
+                None => true,
+            })
+            .filter(|poll| match &query.tag {
+                Some(tag) => poll.tags.contains(tag),
+                None => true,
+            })
+            .filter(|poll| match &title_filter {
+                Some(needle) => poll.title.to_lowercase().contains(needle.as_str()),
+                None => true,
+            })
+            .map(|poll| PollCursor {
+                sort_value: match query.sort_by {
+                    PollSortKey::Id => poll.id,
+                    PollSortKey::StartTime => poll.start_time,
+                    PollSortKey::EndTime => poll.end_time,
+                    PollSortKey::VoteCount => self.results[&poll.id].total_votes,
+                },
+                poll_id: poll.id,
+            })
+            .collect();
+        
+        query::paginate(entries, query)
+    }
+    
+    // Get detailed vote results with percentage. Floating point is a display
+    // convenience only; use get_ordered_results for exact basis points.
     pub fn get_detailed_results(&self, poll_id: u64) -> Result<HashMap<String, (u64, f64)>, ContractError> {
         let poll = match self.polls.get(&poll_id) {
             Some(p) => p,
             None => return Err(ContractError::PollNotFound),
         };
         
+        if poll.is_cancelled() {
+            return Err(ContractError::PollCancelled);
+        }
+        
+        // Sums of grade indices are not votes; see get_majority_judgment_results
+        if matches!(poll.settings.method, PollMethod::MajorityJudgment { .. }) {
+            return Err(ContractError::MethodMismatch);
+        }
+        
         let results = match self.results.get(&poll_id) {
             Some(r) => r,
             None => return Err(ContractError::PollNotFound),
         };
         
         let mut detailed_results = HashMap::new();
-        let total_votes = results.total_votes;
+        let total: u128 = results.counts.values().map(|count| *count as u128).sum();
         
         for (option_idx, count) in &results.counts {
-            let option_name = &poll.options[*option_idx as usize];
-            let percentage = if total_votes > 0 {
-                (*count as f64 / total_votes as f64) * 100.0
+            let option_name = &poll.options[*option_idx as usize].label;
+            let percentage = if total > 0 {
+                (*count as f64 / total as f64) * 100.0
             } else {
                 0.0
             };
@@ -221,6 +747,33 @@ impl VotingContract {
         Ok(detailed_results)
     }
     
+    // Get results for every option in index order, with a ranking. Shares
+    // use largest-remainder rounding and always add up to exactly 100.00%.
+    pub fn get_ordered_results(&self, poll_id: u64) -> Result<OrderedResults, ContractError> {
+        self.get_ordered_results_rounded(poll_id, RoundingMode::LargestRemainder)
+    }
+    
+    // Get ordered results with shares rounded using the given mode
+    pub fn get_ordered_results_rounded(
+        &self,
+        poll_id: u64,
+        rounding: RoundingMode,
+    ) -> Result<OrderedResults, ContractError> {
+        let poll = self.get_poll(poll_id)?;
+        
+        if poll.is_cancelled() {
+            return Err(ContractError::PollCancelled);
+        }
+        
+        // Sums of grade indices are not votes; see get_majority_judgment_results
+        if matches!(poll.settings.method, PollMethod::MajorityJudgment { .. }) {
+            return Err(ContractError::MethodMismatch);
+        }
+        
+        let results = self.get_results(poll_id)?;
+        Ok(OrderedResults::new(poll, results, rounding))
+    }
+    
     // Check if an address has voted in a poll
     pub fn has_voted(&self, poll_id: u64, wallet_address: &str) -> Result<bool, ContractError> {
         let poll_votes = match self.votes.get(&poll_id) {
@@ -230,4 +783,1506 @@ impl VotingContract {
         
         Ok(poll_votes.contains_key(wallet_address))
     }
-}
\ No newline at end of file
+    
+    // Freeze a poll's results once voting is over and record the state root
+    // they are committed under
+    pub fn finalize_poll(&mut self, poll_id: u64) -> Result<TallyCommitment, ContractError> {
+        let vote_root = self.vote_root(poll_id);
+        let poll = match self.polls.get_mut(&poll_id) {
+            Some(p) => p,
+            None => return Err(ContractError::PollNotFound),
+        };
+        
+        if poll.is_cancelled() {
+            return Err(ContractError::PollCancelled);
+        }
+        
+        if poll.finalized {
+            return Err(ContractError::PollAlreadyFinalized);
+        }
+        
+        // Voting must be over, either by time or by closure
+        if poll.active && self.clock.now() <= poll.end_time {
+            return Err(ContractError::PollNotEnded);
+        }
+        
+        poll.active = false;
+        poll.finalized = true;
+        
+        // Votes can no longer change, so a seeded draw can now be fixed
+        if let TieBreakPolicy::SeededDraw { seed } = poll.settings.tie_break {
+            poll.draw_seed = Some(results::draw_seed(&seed, &vote_root));
+        }
+        
+        // A leader short of the required majority, or an unresolved tie, calls
+        // for a runoff
+        let results = &self.results[&poll_id];
+        let majority = poll.settings.runoff.and_then(|config| {
+            results::majority_runoff(poll, results).map(|options| (options, config.duration))
+        });
+        let tie = match (poll.settings.tie_break, results::decide_winner(poll, results)) {
+            (TieBreakPolicy::Runoff { duration }, PollOutcome::Tie { options }) => Some((options, duration)),
+            _ => None,
+        };
+        
+        if let Some((options, duration)) = majority.or(tie) {
+            self.spawn_runoff(poll_id, options, duration);
+        }
+        
+        let (poll_ids, state_tree) = self.state_tree();
+        let poll_index = poll_ids.iter().position(|id| *id == poll_id).unwrap();
+        self.finalized_states.insert(poll_id, FinalizedState {
+            state_root: state_tree.root(),
+            poll_header_hash: merkle::poll_header_hash(&self.polls[&poll_id]),
+            poll_proof: state_tree.proof(poll_index).unwrap(),
+        });
+        
+        let commitment = self.get_tally_commitment(poll_id)?;
+        
+        // A passed proposal runs its actions straight away; if they fail they
+        // are rolled back and can be retried with execute_proposal
+        if self.executable_proposals.contains_key(&poll_id)
+            && self.proposal_passed(poll_id)
+            && self.execute_proposal(poll_id).is_err()
+        {
+            self.events.push(Event::ProposalExecutionFailed { poll_id });
+        }
+        
+        Ok(commitment)
+    }
+    
+    // Create a poll between Approve and Reject carrying actions that run once
+    // it passes finalization. The poll follows the configured proposal rules
+    // rather than caller-chosen settings.
+    pub fn create_executable_proposal(
+        &mut self,
+        creator: String,
+        title: String,
+        description: String,
+        actions: Vec<AdminAction>,
+    ) -> Result<u64, ContractError> {
+        self.authorize(&creator, Permission::ProposeActions)?;
+        self.validate_proposal(&actions)?;
+        
+        Ok(self.open_proposal(creator, title, description, actions))
+    }
+    
+    // Check a list of actions can be put to a vote
+    fn validate_proposal(&self, actions: &[AdminAction]) -> Result<(), ContractError> {
+        if actions.is_empty() {
+            return Err(ContractError::InvalidSettings);
+        }
+        
+        if self.config.proposal_electorate.is_empty() {
+            return Err(ContractError::InvalidConfig);
+        }
+        
+        for action in actions {
+            if let AdminAction::Callback { name, .. } = action {
+                if !self.callbacks.contains_key(name) {
+                    return Err(ContractError::CallbackNotFound);
+                }
+            }
+        }
+        
+        Ok(())
+    }
+    
+    // Open a validated proposal's poll: it starts now, stays open for the
+    // configured duration and only the configured electorate may vote
+    fn open_proposal(&mut self, creator: String, title: String, description: String, actions: Vec<AdminAction>) -> u64 {
+        let start_time = self.clock.now();
+        let electorate = self.config.proposal_electorate.clone();
+        // Round up, so the quorum is never below the configured share
+        let quorum = (electorate.len() as u64 * self.config.proposal_quorum_bps as u64).div_ceil(BASIS_POINTS as u64);
+        
+        let poll_id = self.insert_poll(Poll {
+            id: 0,
+            title,
+            description,
+            options: governance::PROPOSAL_OPTIONS.iter().map(|label| PollOption::new(*label)).collect(),
+            creator,
+            start_time,
+            end_time: start_time.saturating_add(self.config.proposal_duration),
+            active: true,
+            finalized: false,
+            tags: Vec::new(),
+            settings: PollSettings {
+                eligible_voters: Some(electorate),
+                ..PollSettings::default()
+            },
+            parent_poll: None,
+            runoff_poll: None,
+            edit_history: Vec::new(),
+            cancellation: None,
+            draw_seed: None,
+            ballot: None,
+        });
+        self.executable_proposals.insert(poll_id, ExecutableProposal {
+            poll_id,
+            actions,
+            quorum,
+            executed_at: None,
+        });
+        
+        poll_id
+    }
+    
+    // Run a passed proposal's actions, all or nothing. Anyone may call this,
+    // for example to retry actions that failed at finalization.
+    pub fn execute_proposal(&mut self, poll_id: u64) -> Result<(), ContractError> {
+        let proposal = self.executable_proposals.get(&poll_id).ok_or(ContractError::ProposalNotFound)?;
+        
+        if proposal.executed_at.is_some() {
+            return Err(ContractError::ProposalAlreadyExecuted);
+        }
+        
+        let poll = &self.polls[&poll_id];
+        if poll.is_cancelled() {
+            return Err(ContractError::PollCancelled);
+        }
+        if !poll.finalized {
+            return Err(ContractError::PollNotFinalized);
+        }
+        if !self.proposal_passed(poll_id) {
+            return Err(ContractError::ProposalRejected);
+        }
+        
+        let actions = proposal.actions.clone();
+        let proposer = poll.creator.clone();
+        self.apply_actions(&actions, &proposer)?;
+        
+        self.executable_proposals.get_mut(&poll_id).unwrap().executed_at = Some(self.clock.now());
+        self.events.push(Event::ProposalExecuted { poll_id });
+        
+        Ok(())
+    }
+    
+    // Get the actions attached to a poll and whether they have run
+    pub fn get_executable_proposal(&self, poll_id: u64) -> Result<&ExecutableProposal, ContractError> {
+        self.executable_proposals.get(&poll_id).ok_or(ContractError::ProposalNotFound)
+    }
+    
+    // Register code that passed proposals can call by name (admins only).
+    // Once a council exists the code is only staged, and cannot be called
+    // until the council enables it with `AdminAction::EnableCallback`.
+    pub fn register_callback(&mut self, name: String, callback: Callback, caller: String) -> Result<(), ContractError> {
+        let staged = match self.authorize(&caller, Permission::ManageCallbacks) {
+            Ok(()) => false,
+            Err(ContractError::CouncilApprovalRequired) => true,
+            Err(err) => return Err(err),
+        };
+        
+        if name.trim().is_empty() {
+            return Err(ContractError::InvalidSettings);
+        }
+        
+        if staged {
+            self.staged_callbacks.insert(name, callback);
+        } else {
+            self.callbacks.insert(name, callback);
+        }
+        
+        Ok(())
+    }
+    
+    // Whether a proposal's poll reached its quorum with more Approve than
+    // Reject votes
+    fn proposal_passed(&self, poll_id: u64) -> bool {
+        let results = &self.results[&poll_id];
+        results.total_votes >= self.executable_proposals[&poll_id].quorum && results.counts[&0] > results.counts[&1]
+    }
+    
+    // Elected set and per-round transfer report of an STV poll
+    pub fn get_stv_results(&self, poll_id: u64) -> Result<StvResults, ContractError> {
+        let poll = self.get_poll(poll_id)?;
+        
+        if poll.is_cancelled() {
+            return Err(ContractError::PollCancelled);
+        }
+        
+        match poll.settings.method {
+            PollMethod::Stv { seats } => Ok(StvResults::new(
+                poll_id,
+                poll.options.len(),
+                seats,
+                &self.results[&poll_id].rankings,
+            )),
+            _ => Err(ContractError::MethodMismatch),
+        }
+    }
+    
+    // Score totals, finalists and runoff preferences of a STAR poll
+    pub fn get_star_results(&self, poll_id: u64) -> Result<StarResults, ContractError> {
+        let poll = self.get_poll(poll_id)?;
+        
+        if poll.is_cancelled() {
+            return Err(ContractError::PollCancelled);
+        }
+        
+        if poll.settings.method != PollMethod::Star {
+            return Err(ContractError::MethodMismatch);
+        }
+        
+        Ok(StarResults::new(poll, &self.results[&poll_id]))
+    }
+    
+    // Grade distributions, median grades and ranking of a majority judgment poll
+    pub fn get_majority_judgment_results(&self, poll_id: u64) -> Result<MajorityJudgmentResults, ContractError> {
+        let poll = self.get_poll(poll_id)?;
+        
+        if poll.is_cancelled() {
+            return Err(ContractError::PollCancelled);
+        }
+        
+        match &poll.settings.method {
+            PollMethod::MajorityJudgment { grades } => {
+                Ok(MajorityJudgmentResults::new(poll, &self.results[&poll_id], grades))
+            }
+            _ => Err(ContractError::MethodMismatch),
+        }
+    }
+    
+    // Pairwise matrix, Schulze and Ranked Pairs tallies of a ranked poll,
+    // whichever rule decides its winner
+    pub fn get_condorcet_results(&self, poll_id: u64) -> Result<CondorcetResults, ContractError> {
+        let poll = self.get_poll(poll_id)?;
+        
+        if poll.is_cancelled() {
+            return Err(ContractError::PollCancelled);
+        }
+        
+        if !poll.settings.method.uses_rankings() {
+            return Err(ContractError::MethodMismatch);
+        }
+        
+        Ok(CondorcetResults::new(poll_id, &self.results[&poll_id].pairwise))
+    }
+    
+    // Points totals of a ranked poll under any positional rule, whichever
+    // rule decides its winner
+    pub fn get_positional_results(
+        &self,
+        poll_id: u64,
+        rule: PositionalRule,
+    ) -> Result<PositionalResults, ContractError> {
+        let poll = self.get_poll(poll_id)?;
+        
+        if poll.is_cancelled() {
+            return Err(ContractError::PollCancelled);
+        }
+        
+        if !poll.settings.method.uses_rankings() {
+            return Err(ContractError::MethodMismatch);
+        }
+        
+        if !rule.is_valid() {
+            return Err(ContractError::InvalidSettings);
+        }
+        
+        Ok(PositionalResults::new(poll, &self.results[&poll_id], rule))
+    }
+    
+    // Get the winner of a poll whose voting is over, applying its tie-break policy
+    pub fn get_winner(&self, poll_id: u64) -> Result<PollOutcome, ContractError> {
+        let poll = self.get_poll(poll_id)?;
+        
+        if poll.is_cancelled() {
+            return Err(ContractError::PollCancelled);
+        }
+        
+        if poll.active && self.clock.now() <= poll.end_time {
+            return Err(ContractError::PollNotEnded);
+        }
+        
+        Ok(results::decide_winner(poll, &self.results[&poll_id]))
+    }
+    
+    // Open a runoff between some of a finalized poll's options and link the two polls
+    fn spawn_runoff(&mut self, parent_id: u64, option_indices: Vec<u32>, duration: u64) -> u64 {
+        let parent = &self.polls[&parent_id];
+        let start_time = self.clock.now();
+        
+        let runoff = Poll {
+            id: 0,
+            title: format!("{} (runoff)", parent.title),
+            description: parent.description.clone(),
+            options: option_indices
+                .iter()
+                .map(|index| parent.options[*index as usize].clone())
+                .collect(),
+            creator: parent.creator.clone(),
+            start_time,
+            end_time: start_time.saturating_add(duration),
+            active: true,
+            finalized: false,
+            tags: parent.tags.clone(),
+            // Same eligibility and rules, but a runoff never spawns another
+            // majority runoff
+            settings: PollSettings {
+                runoff: None,
+                ..parent.settings.clone()
+            },
+            parent_poll: Some(parent_id),
+            runoff_poll: None,
+            edit_history: Vec::new(),
+            cancellation: None,
+            draw_seed: None,
+            ballot: None,
+        };
+        
+        let runoff_id = self.insert_poll(runoff);
+        self.polls.get_mut(&parent_id).unwrap().runoff_poll = Some(runoff_id);
+        runoff_id
+    }
+    
+    // Get the hash of a poll's current tally
+    pub fn get_results_hash(&self, poll_id: u64) -> Result<Hash, ContractError> {
+        let results = self.get_results(poll_id)?;
+        Ok(merkle::results_hash(poll_id, results))
+    }
+    
+    // Get the commitment to a finalized poll's results, suitable for anchoring
+    pub fn get_tally_commitment(&self, poll_id: u64) -> Result<TallyCommitment, ContractError> {
+        let results_hash = self.get_results_hash(poll_id)?;
+        
+        let state_root = match self.finalized_states.get(&poll_id) {
+            Some(finalized) => finalized.state_root,
+            None => return Err(ContractError::PollNotFinalized),
+        };
+        
+        Ok(TallyCommitment {
+            poll_id,
+            results_hash,
+            state_root,
+        })
+    }
+    
+    // Create a ballot of several questions sharing one voting window and
+    // voter list. Each question gets its own poll, in question order.
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_ballot(
+        &mut self,
+        creator: String,
+        title: String,
+        description: String,
+        questions: Vec<Question>,
+        start_time: u64,
+        end_time: u64,
+        eligible_voters: Option<BTreeSet<String>>,
+    ) -> Result<u64, ContractError> {
+        self.authorize(&creator, Permission::CreatePoll)?;
+        
+        if questions.is_empty() {
+            return Err(ContractError::InvalidOption);
+        }
+        
+        // Check every question before creating any of their polls
+        let questions: Vec<(String, Vec<PollOption>, PollMethod)> = questions
+            .into_iter()
+            .map(|question| {
+                let options: Vec<PollOption> = question.options.into_iter().map(PollOption::from).collect();
+                (question.prompt, options, question.method.poll_method())
+            })
+            .collect();
+        for (_, options, method) in &questions {
+            self.validate_options(options)?;
+            validate_method(method, options.len())?;
+        }
+        
+        if start_time >= end_time {
+            return Err(ContractError::InvalidTimeRange);
+        }
+        
+        let ballot_id = self.ballot_counter;
+        self.ballot_counter += 1;
+        
+        let polls = questions
+            .into_iter()
+            .map(|(prompt, options, method)| {
+                self.insert_poll(Poll {
+                    id: 0,
+                    title: prompt,
+                    description: String::new(),
+                    options,
+                    creator: creator.clone(),
+                    start_time,
+                    end_time,
+                    active: true,
+                    finalized: false,
+                    tags: Vec::new(),
+                    settings: PollSettings {
+                        method,
+                        eligible_voters: eligible_voters.clone(),
+                        ..PollSettings::default()
+                    },
+                    parent_poll: None,
+                    runoff_poll: None,
+                    edit_history: Vec::new(),
+                    cancellation: None,
+                    draw_seed: None,
+                    ballot: Some(ballot_id),
+                })
+            })
+            .collect();
+        
+        self.ballot_voters.insert(ballot_id, BTreeSet::new());
+        self.ballots.insert(ballot_id, Ballot {
+            id: ballot_id,
+            title,
+            description,
+            polls,
+            creator,
+            start_time,
+            end_time,
+            active: true,
+            eligible_voters,
+        });
+        
+        Ok(ballot_id)
+    }
+    
+    // Submit answers to every question of a ballot at once. Nothing is
+    // counted unless every answer is valid.
+    pub fn cast_ballot(
+        &mut self,
+        ballot_id: u64,
+        wallet_address: String,
+        answers: Vec<Answer>,
+    ) -> Result<(), ContractError> {
+        let ballot = self.get_ballot(ballot_id)?;
+        
+        if !ballot.active {
+            return Err(ContractError::PollNotActive);
+        }
+        
+        let now = self.clock.now();
+        if now < ballot.start_time {
+            return Err(ContractError::PollNotActive);
+        }
+        if now > ballot.end_time {
+            return Err(ContractError::PollAlreadyEnded);
+        }
+        
+        if let Some(eligible) = &ballot.eligible_voters {
+            if !eligible.contains(&wallet_address) {
+                return Err(ContractError::NotEligible);
+            }
+        }
+        
+        if self.ballot_voters[&ballot_id].contains(&wallet_address) {
+            return Err(ContractError::AlreadyVoted);
+        }
+        
+        if answers.len() != ballot.polls.len() {
+            return Err(ContractError::InvalidOption);
+        }
+        
+        // Check every answer before counting any; abstentions cast no vote
+        let mut votes = Vec::new();
+        for (poll_id, answer) in ballot.polls.iter().zip(&answers) {
+            if let Some(choice) = answer.to_choice(&self.polls[poll_id].settings.method)? {
+                let weight = self.check_vote(*poll_id, &wallet_address, &choice)?;
+                votes.push((*poll_id, choice, weight));
+            }
+        }
+        
+        for (poll_id, choice, weight) in votes {
+            self.record_vote(poll_id, wallet_address.clone(), choice, weight)?;
+        }
+        self.ballot_voters.get_mut(&ballot_id).unwrap().insert(wallet_address);
+        
+        Ok(())
+    }
+    
+    // Get ballot information
+    pub fn get_ballot(&self, ballot_id: u64) -> Result<&Ballot, ContractError> {
+        match self.ballots.get(&ballot_id) {
+            Some(ballot) => Ok(ballot),
+            None => Err(ContractError::BallotNotFound),
+        }
+    }
+    
+    // Get per-question results and turnout for a ballot, read from its
+    // question polls
+    pub fn get_ballot_results(&self, ballot_id: u64) -> Result<BallotResults, ContractError> {
+        let ballot = self.get_ballot(ballot_id)?;
+        let turnout = self.ballot_voters[&ballot_id].len() as u64;
+        
+        let questions = ballot
+            .polls
+            .iter()
+            .map(|poll_id| {
+                let answered = self.votes[poll_id].len() as u64;
+                QuestionResults {
+                    counts: self.results[poll_id].counts.clone(),
+                    turnout: answered,
+                    abstentions: turnout - answered,
+                }
+            })
+            .collect();
+        
+        Ok(BallotResults { turnout, questions })
+    }
+    
+    // Check if an address has submitted a ballot
+    pub fn has_cast_ballot(&self, ballot_id: u64, wallet_address: &str) -> Result<bool, ContractError> {
+        match self.ballot_voters.get(&ballot_id) {
+            Some(voters) => Ok(voters.contains(wallet_address)),
+            None => Err(ContractError::BallotNotFound),
+        }
+    }
+    
+    // Close a ballot and its question polls (only its creator, a moderator
+    // or an admin can do this)
+    pub fn close_ballot(&mut self, ballot_id: u64, caller: String) -> Result<(), ContractError> {
+        let creator = match self.ballots.get(&ballot_id) {
+            Some(b) => &b.creator,
+            None => return Err(ContractError::BallotNotFound),
+        };
+        
+        if *creator != caller {
+            self.authorize(&caller, Permission::CloseAnyPoll)?;
+        }
+        
+        let ballot = self.ballots.get_mut(&ballot_id).unwrap();
+        ballot.active = false;
+        for poll_id in &ballot.polls {
+            self.polls.get_mut(poll_id).unwrap().active = false;
+        }
+        
+        Ok(())
+    }
+    
+    // Void a ballot by cancelling every question poll, recording why (only
+    // its creator, a moderator or an admin can do this)
+    pub fn cancel_ballot(&mut self, ballot_id: u64, caller: String, reason: String) -> Result<(), ContractError> {
+        let ballot = self.get_ballot(ballot_id)?;
+        
+        if ballot.creator != caller {
+            self.authorize(&caller, Permission::CloseAnyPoll)?;
+        }
+        
+        for poll_id in &ballot.polls {
+            check_cancellation(&self.polls[poll_id], &reason)?;
+        }
+        
+        let ballot = self.ballots.get_mut(&ballot_id).unwrap();
+        ballot.active = false;
+        for poll_id in ballot.polls.clone() {
+            self.void_poll(poll_id, &caller, &reason);
+        }
+        
+        Ok(())
+    }
+    
+    // Create a conviction pool with no end time that funds proposals from
+    // `config.funds` as conviction builds behind them
+    pub fn create_conviction_pool(
+        &mut self,
+        creator: String,
+        title: String,
+        description: String,
+        config: ConvictionConfig,
+    ) -> Result<u64, ContractError> {
+        self.authorize(&creator, Permission::CreatePoll)?;
+        
+        if !config.is_valid() {
+            return Err(ContractError::InvalidSettings);
+        }
+        
+        let pool_id = self.conviction_pool_counter;
+        self.conviction_pool_counter += 1;
+        
+        self.conviction_pools.insert(pool_id, ConvictionPool {
+            id: pool_id,
+            title,
+            description,
+            creator,
+            available_funds: config.funds,
+            config,
+            proposals: Vec::new(),
+            stakes: BTreeMap::new(),
+            active: true,
+        });
+        
+        Ok(pool_id)
+    }
+    
+    // Ask a conviction pool to pay `requested` to `beneficiary`
+    pub fn submit_funding_proposal(
+        &mut self,
+        pool_id: u64,
+        proposer: String,
+        title: String,
+        beneficiary: String,
+        requested: u64,
+    ) -> Result<u32, ContractError> {
+        let now = self.clock.now();
+        let pool = self.open_conviction_pool(pool_id)?;
+        
+        if title.trim().is_empty() {
+            return Err(ContractError::EmptyLabel);
+        }
+        if title.len() > models::MAX_LABEL_LEN {
+            return Err(ContractError::OptionTooLarge);
+        }
+        if requested == 0 {
+            return Err(ContractError::InvalidSettings);
+        }
+        if requested > pool.available_funds {
+            return Err(ContractError::InsufficientFunds);
+        }
+        
+        let proposal_id = pool.proposals.len() as u32;
+        pool.proposals.push(FundingProposal {
+            id: proposal_id,
+            title,
+            proposer,
+            beneficiary,
+            requested,
+            staked: 0,
+            conviction: 0,
+            updated_at: now,
+            passed_at: None,
+        });
+        
+        Ok(proposal_id)
+    }
+    
+    // Stake weight on a funding proposal; its conviction then grows toward
+    // the new stake
+    pub fn stake_conviction(
+        &mut self,
+        pool_id: u64,
+        proposal_id: u32,
+        wallet_address: String,
+        amount: u64,
+    ) -> Result<(), ContractError> {
+        let now = self.clock.now();
+        let pool = self.open_conviction_pool(pool_id)?;
+        
+        let weight = *pool.config.weights.get(&wallet_address).ok_or(ContractError::NotEligible)?;
+        open_proposal(pool, proposal_id)?;
+        
+        let staked = pool.staked_by(&wallet_address).checked_add(amount);
+        if staked.is_none_or(|staked| staked > weight) {
+            return Err(ContractError::InsufficientWeight);
+        }
+        
+        let half_life = pool.config.half_life;
+        let proposal = &mut pool.proposals[proposal_id as usize];
+        proposal.accrue(now, half_life);
+        proposal.staked += amount;
+        *pool.stakes.entry(wallet_address).or_default().entry(proposal_id).or_insert(0) += amount;
+        
+        Ok(())
+    }
+    
+    // Withdraw weight from a funding proposal; its conviction then decays
+    // toward the remaining stake
+    pub fn unstake_conviction(
+        &mut self,
+        pool_id: u64,
+        proposal_id: u32,
+        wallet_address: String,
+        amount: u64,
+    ) -> Result<(), ContractError> {
+        let now = self.clock.now();
+        let pool = self.open_conviction_pool(pool_id)?;
+        
+        open_proposal(pool, proposal_id)?;
+        
+        let stakes = pool.stakes.get_mut(&wallet_address).ok_or(ContractError::InsufficientWeight)?;
+        let stake = stakes.get_mut(&proposal_id).ok_or(ContractError::InsufficientWeight)?;
+        if *stake < amount {
+            return Err(ContractError::InsufficientWeight);
+        }
+        
+        *stake -= amount;
+        if *stake == 0 {
+            stakes.remove(&proposal_id);
+        }
+        if stakes.is_empty() {
+            pool.stakes.remove(&wallet_address);
+        }
+        
+        let half_life = pool.config.half_life;
+        let proposal = &mut pool.proposals[proposal_id as usize];
+        proposal.accrue(now, half_life);
+        proposal.staked -= amount;
+        
+        Ok(())
+    }
+    
+    // Pay out a funding proposal whose conviction has reached its threshold.
+    // Anyone may call this; the stake behind the proposal is released.
+    pub fn execute_funding_proposal(&mut self, pool_id: u64, proposal_id: u32) -> Result<(), ContractError> {
+        let now = self.clock.now();
+        let pool = self.open_conviction_pool(pool_id)?;
+        
+        let proposal = open_proposal(pool, proposal_id)?;
+        if proposal.requested > pool.available_funds {
+            return Err(ContractError::InsufficientFunds);
+        }
+        
+        let conviction = proposal.conviction_at(now, pool.config.half_life);
+        if pool.threshold(proposal.requested).is_none_or(|threshold| conviction < threshold) {
+            return Err(ContractError::ThresholdNotReached);
+        }
+        
+        let half_life = pool.config.half_life;
+        let proposal = &mut pool.proposals[proposal_id as usize];
+        proposal.accrue(now, half_life);
+        proposal.staked = 0;
+        proposal.passed_at = Some(now);
+        let (amount, beneficiary) = (proposal.requested, proposal.beneficiary.clone());
+        
+        pool.available_funds -= amount;
+        for stakes in pool.stakes.values_mut() {
+            stakes.remove(&proposal_id);
+        }
+        pool.stakes.retain(|_, stakes| !stakes.is_empty());
+        
+        self.events.push(Event::FundingProposalPassed {
+            pool_id,
+            proposal_id,
+            beneficiary,
+            amount,
+        });
+        
+        Ok(())
+    }
+    
+    // Get conviction pool information, including its proposals and stakes
+    pub fn get_conviction_pool(&self, pool_id: u64) -> Result<&ConvictionPool, ContractError> {
+        self.conviction_pools.get(&pool_id).ok_or(ContractError::PoolNotFound)
+    }
+    
+    // Stake, conviction and threshold of a funding proposal at a Unix
+    // timestamp, assuming stakes do not change before then
+    pub fn get_conviction_status(
+        &self,
+        pool_id: u64,
+        proposal_id: u32,
+        timestamp: u64,
+    ) -> Result<ConvictionStatus, ContractError> {
+        let pool = self.get_conviction_pool(pool_id)?;
+        let proposal = pool
+            .proposals
+            .get(proposal_id as usize)
+            .ok_or(ContractError::FundingProposalNotFound)?;
+        
+        let conviction = proposal.conviction_at(timestamp, pool.config.half_life);
+        let threshold = pool.threshold(proposal.requested);
+        
+        Ok(ConvictionStatus {
+            staked: proposal.staked,
+            conviction,
+            threshold,
+            passable: pool.active
+                && proposal.passed_at.is_none()
+                && threshold.is_some_and(|threshold| conviction >= threshold),
+        })
+    }
+    
+    // Close a conviction pool (only its creator, a moderator or an admin can
+    // do this); unpaid proposals can no longer pass
+    pub fn close_conviction_pool(&mut self, pool_id: u64, caller: String) -> Result<(), ContractError> {
+        let creator = &self.get_conviction_pool(pool_id)?.creator;
+        
+        if *creator != caller {
+            self.authorize(&caller, Permission::CloseAnyPoll)?;
+        }
+        
+        self.conviction_pools.get_mut(&pool_id).unwrap().active = false;
+        
+        Ok(())
+    }
+    
+    // Lock a balance until `unlock_time` for voting power that decays
+    // linearly to zero at unlock (in a real deployment the balance would be
+    // transferred into escrow)
+    pub fn create_lock(&mut self, wallet_address: String, amount: u64, unlock_time: u64) -> Result<(), ContractError> {
+        let now = self.clock.now();
+        
+        if self.get_lock(&wallet_address).is_ok() {
+            return Err(ContractError::LockExists);
+        }
+        if amount == 0 {
+            return Err(ContractError::InvalidLock);
+        }
+        self.check_unlock_time(now, unlock_time)?;
+        if amount > self.get_free_balance(&wallet_address) {
+            return Err(ContractError::InsufficientBalance);
+        }
+        
+        let lock = Lock {
+            amount,
+            unlock_time,
+            max_duration: self.config.max_lock_duration,
+        };
+        self.locks.entry(wallet_address).or_default().push(now, Some(lock));
+        
+        Ok(())
+    }
+    
+    // Add to the balance of an unexpired lock
+    pub fn increase_lock_amount(&mut self, wallet_address: String, amount: u64) -> Result<(), ContractError> {
+        let now = self.clock.now();
+        let mut lock = *self.get_lock(&wallet_address)?;
+        
+        if lock.unlock_time <= now {
+            return Err(ContractError::LockNotFound);
+        }
+        lock.amount = match lock.amount.checked_add(amount) {
+            Some(total) if amount > 0 => total,
+            _ => return Err(ContractError::InvalidLock),
+        };
+        if amount > self.get_free_balance(&wallet_address) {
+            return Err(ContractError::InsufficientBalance);
+        }
+        lock.max_duration = self.config.max_lock_duration;
+        
+        self.locks.get_mut(&wallet_address).unwrap().push(now, Some(lock));
+        
+        Ok(())
+    }
+    
+    // Push back the unlock time of an unexpired lock
+    pub fn extend_lock(&mut self, wallet_address: String, unlock_time: u64) -> Result<(), ContractError> {
+        let now = self.clock.now();
+        let mut lock = *self.get_lock(&wallet_address)?;
+        
+        if lock.unlock_time <= now {
+            return Err(ContractError::LockNotFound);
+        }
+        if unlock_time <= lock.unlock_time {
+            return Err(ContractError::InvalidLock);
+        }
+        self.check_unlock_time(now, unlock_time)?;
+        lock.unlock_time = unlock_time;
+        lock.max_duration = self.config.max_lock_duration;
+        
+        self.locks.get_mut(&wallet_address).unwrap().push(now, Some(lock));
+        
+        Ok(())
+    }
+    
+    // Release an expired lock and return its balance
+    pub fn withdraw_lock(&mut self, wallet_address: String) -> Result<u64, ContractError> {
+        let now = self.clock.now();
+        let lock = *self.get_lock(&wallet_address)?;
+        
+        if now < lock.unlock_time {
+            return Err(ContractError::LockNotExpired);
+        }
+        
+        self.locks.get_mut(&wallet_address).unwrap().push(now, None);
+        
+        Ok(lock.amount)
+    }
+    
+    // Get a wallet's current lock
+    pub fn get_lock(&self, wallet_address: &str) -> Result<&Lock, ContractError> {
+        self.locks
+            .get(wallet_address)
+            .and_then(LockHistory::current)
+            .ok_or(ContractError::LockNotFound)
+    }
+    
+    // Set the balance a wallet may lock (admins only, while there is no
+    // council). It cannot drop below what the wallet has locked.
+    pub fn set_balance(&mut self, wallet_address: String, balance: u64, caller: String) -> Result<(), ContractError> {
+        self.authorize(&caller, Permission::ManageBalances)?;
+        self.check_balance(&wallet_address, balance)?;
+        self.balances.insert(wallet_address, balance);
+        Ok(())
+    }
+    
+    // Get the balance a wallet may lock, locked or not
+    pub fn get_balance(&self, wallet_address: &str) -> u64 {
+        self.balances.get(wallet_address).copied().unwrap_or(0)
+    }
+    
+    // Get the part of a wallet's balance that is not locked
+    pub fn get_free_balance(&self, wallet_address: &str) -> u64 {
+        let locked = self.get_lock(wallet_address).map_or(0, |lock| lock.amount);
+        self.get_balance(wallet_address).saturating_sub(locked)
+    }
+    
+    // Check a new balance still covers the wallet's lock
+    fn check_balance(&self, wallet_address: &str, balance: u64) -> Result<(), ContractError> {
+        if self.get_lock(wallet_address).is_ok_and(|lock| lock.amount > balance) {
+            return Err(ContractError::InsufficientBalance);
+        }
+        
+        Ok(())
+    }
+    
+    // Vote-escrow power of a wallet at a Unix timestamp, using the lock as it
+    // stood then
+    pub fn get_voting_power(&self, wallet_address: &str, timestamp: u64) -> u64 {
+        self.locks.get(wallet_address).map_or(0, |history| history.power_at(timestamp))
+    }
+    
+    // Check an unlock time lies in the future, within the longest lock
+    fn check_unlock_time(&self, now: u64, unlock_time: u64) -> Result<(), ContractError> {
+        if unlock_time <= now || unlock_time - now > self.config.max_lock_duration {
+            return Err(ContractError::InvalidLock);
+        }
+        
+        Ok(())
+    }
+    
+    // Look up a conviction pool that is still open
+    fn open_conviction_pool(&mut self, pool_id: u64) -> Result<&mut ConvictionPool, ContractError> {
+        let pool = self.conviction_pools.get_mut(&pool_id).ok_or(ContractError::PoolNotFound)?;
+        
+        if !pool.active {
+            return Err(ContractError::PollNotActive);
+        }
+        
+        Ok(pool)
+    }
+    
+    // Grant a role to an address (admins only)
+    pub fn grant_role(&mut self, role: Role, account: String, caller: String) -> Result<(), ContractError> {
+        self.authorize(&caller, Permission::ManageRoles)?;
+        self.access.grant(role, account);
+        Ok(())
+    }
+    
+    // Revoke a role from an address (admins only)
+    pub fn revoke_role(&mut self, role: Role, account: String, caller: String) -> Result<(), ContractError> {
+        self.authorize(&caller, Permission::ManageRoles)?;
+        self.access.revoke(role, &account);
+        Ok(())
+    }
+    
+    // Check if an address holds a role (the owner always holds Admin)
+    pub fn has_role(&self, role: Role, account: &str) -> bool {
+        (role == Role::Admin && account == self.owner) || self.access.has_role(role, account)
+    }
+    
+    // Get all addresses explicitly granted a role
+    pub fn get_role_members(&self, role: Role) -> Vec<String> {
+        self.access.members(role)
+    }
+    
+    // Get every vote cast in a poll, sorted by wallet (admins and auditors only)
+    pub fn get_poll_votes(&self, poll_id: u64, caller: &str) -> Result<Vec<(String, VoteChoice)>, ContractError> {
+        self.authorize(caller, Permission::ExportVotes)?;
+        
+        if !self.polls.contains_key(&poll_id) {
+            return Err(ContractError::PollNotFound);
+        }
+        
+        Ok(self
+            .vote_tree(poll_id)
+            .0
+            .into_iter()
+            .map(|(wallet, choice)| (wallet.to_string(), choice.clone()))
+            .collect())
+    }
+    
+    // Get the current contract owner
+    pub fn get_owner(&self) -> &str {
+        &self.owner
+    }
+    
+    // Get the address nominated to become owner, if any
+    pub fn get_pending_owner(&self) -> Option<&str> {
+        self.pending_owner.as_deref()
+    }
+    
+    // Nominate a new owner (owner only). Ownership moves once they accept.
+    pub fn propose_owner(&mut self, new_owner: String, caller: String) -> Result<(), ContractError> {
+        self.authorize(&caller, Permission::TransferOwnership)?;
+        
+        self.pending_owner = Some(new_owner.clone());
+        self.events.push(Event::OwnershipTransferProposed {
+            owner: caller,
+            proposed_owner: new_owner,
+        });
+        
+        Ok(())
+    }
+    
+    // Accept a pending nomination (nominee only)
+    pub fn accept_ownership(&mut self, caller: String) -> Result<(), ContractError> {
+        match &self.pending_owner {
+            Some(pending) if *pending == caller => {}
+            Some(_) => return Err(ContractError::Unauthorized),
+            None => return Err(ContractError::NoPendingTransfer),
+        }
+        
+        self.pending_owner = None;
+        let previous_owner = std::mem::replace(&mut self.owner, caller.clone());
+        self.events.push(Event::OwnershipTransferred {
+            previous_owner,
+            new_owner: caller,
+        });
+        
+        Ok(())
+    }
+    
+    // Withdraw a pending nomination (owner only, while there is no council)
+    pub fn cancel_ownership_transfer(&mut self, caller: String) -> Result<(), ContractError> {
+        self.authorize(&caller, Permission::TransferOwnership)?;
+        
+        let proposed_owner = match self.pending_owner.take() {
+            Some(pending) => pending,
+            None => return Err(ContractError::NoPendingTransfer),
+        };
+        
+        self.events.push(Event::OwnershipTransferCancelled {
+            owner: caller,
+            proposed_owner,
+        });
+        
+        Ok(())
+    }
+    
+    // Get all events emitted so far, oldest first
+    pub fn get_events(&self) -> &[Event] {
+        &self.events
+    }
+    
+    // Get the contract configuration
+    pub fn get_config(&self) -> &ContractConfig {
+        &self.config
+    }
+    
+    // Change a configuration parameter (admins only, while there is no council)
+    pub fn set_config(&mut self, param: ConfigParam, caller: String) -> Result<(), ContractError> {
+        self.authorize(&caller, Permission::ManageConfig)?;
+        self.config.apply(&param)
+    }
+    
+    // Get the admin council, if one has been set
+    pub fn get_council(&self) -> Option<&Council> {
+        self.council.as_ref()
+    }
+    
+    // Install the admin council (admins only). Later changes go through the council itself.
+    pub fn set_council(&mut self, members: Vec<String>, threshold: u32, caller: String) -> Result<(), ContractError> {
+        self.authorize(&caller, Permission::ManageCouncil)?;
+        self.council = Some(Council::new(members, threshold)?);
+        Ok(())
+    }
+    
+    // Propose a privileged action (council members only). The proposer's
+    // approval is counted immediately. Returns the proposal ID.
+    pub fn propose_admin_action(&mut self, action: AdminAction, caller: String) -> Result<u64, ContractError> {
+        let council = match &self.council {
+            Some(c) => c,
+            None => return Err(ContractError::NoCouncil),
+        };
+        
+        if !council.is_member(&caller) {
+            return Err(ContractError::Unauthorized);
+        }
+        
+        let proposal_id = self.admin_proposal_counter;
+        self.admin_proposal_counter += 1;
+        
+        self.admin_proposals.insert(proposal_id, AdminProposal {
+            id: proposal_id,
+            action,
+            proposer: caller.clone(),
+            approvals: Vec::new(),
+            deadline: self.clock.now().saturating_add(self.config.approval_window),
+            executed: false,
+        });
+        self.events.push(Event::AdminActionProposed {
+            proposal_id,
+            proposer: caller.clone(),
+        });
+        
+        // A single-signature council runs the action right away; if it fails,
+        // drop the proposal as if it was never made
+        if let Err(err) = self.approve_admin_action(proposal_id, caller) {
+            self.admin_proposals.remove(&proposal_id);
+            self.admin_proposal_counter -= 1;
+            self.events.pop();
+            return Err(err);
+        }
+        
+        Ok(proposal_id)
+    }
+    
+    // Approve a pending proposal (council members only). The action runs as
+    // soon as the threshold is reached; returns whether it ran.
+    pub fn approve_admin_action(&mut self, proposal_id: u64, caller: String) -> Result<bool, ContractError> {
+        let council = match &self.council {
+            Some(c) => c,
+            None => return Err(ContractError::NoCouncil),
+        };
+        
+        if !council.is_member(&caller) {
+            return Err(ContractError::Unauthorized);
+        }
+        
+        let proposal = match self.admin_proposals.get(&proposal_id) {
+            Some(p) => p,
+            None => return Err(ContractError::ProposalNotFound),
+        };
+        
+        if proposal.executed {
+            return Err(ContractError::ProposalAlreadyExecuted);
+        }
+        
+        if self.clock.now() > proposal.deadline {
+            return Err(ContractError::ProposalExpired);
+        }
+        
+        if proposal.approvals.contains(&caller) {
+            return Err(ContractError::AlreadyApproved);
+        }
+        
+        // Only approvals from current members count, so members removed by
+        // a later SetCouncil no longer carry the proposal. Run the action
+        // before recording the approval that triggered it, so a failing
+        // action leaves the proposal untouched.
+        let approvals = proposal.approvals.iter().filter(|member| council.is_member(member)).count();
+        let reached = approvals + 1 >= council.threshold as usize;
+        if reached {
+            let action = proposal.action.clone();
+            let proposer = proposal.proposer.clone();
+            self.apply_actions(std::slice::from_ref(&action), &proposer)?;
+        }
+        
+        let proposal = self.admin_proposals.get_mut(&proposal_id).unwrap();
+        proposal.approvals.push(caller.clone());
+        proposal.executed = reached;
+        
+        self.events.push(Event::AdminActionApproved {
+            proposal_id,
+            approver: caller,
+        });
+        if reached {
+            self.events.push(Event::AdminActionExecuted { proposal_id });
+        }
+        
+        Ok(reached)
+    }
+    
+    // Get a council proposal
+    pub fn get_admin_proposal(&self, proposal_id: u64) -> Result<&AdminProposal, ContractError> {
+        match self.admin_proposals.get(&proposal_id) {
+            Some(proposal) => Ok(proposal),
+            None => Err(ContractError::ProposalNotFound),
+        }
+    }
+    
+    // Carry out approved actions, all or nothing. Changes to contract state
+    // are staged, and every callback prepares its effect, before anything is
+    // committed; the effects are applied in order after the state.
+    fn apply_actions(&mut self, actions: &[AdminAction], proposer: &str) -> Result<(), ContractError> {
+        let mut access = self.access.clone();
+        let mut config = self.config.clone();
+        let mut council = self.council.clone();
+        let mut pending_owner = self.pending_owner.clone();
+        let mut ownership_events = Vec::new();
+        let mut closed = Vec::new();
+        let mut cancelled: Vec<(u64, &String)> = Vec::new();
+        let mut proposals = Vec::new();
+        let mut enabled = Vec::new();
+        let mut balances = Vec::new();
+        let mut calls = Vec::new();
+        
+        for action in actions {
+            match action {
+                AdminAction::ClosePoll { poll_id } => {
+                    let poll = self.polls.get(poll_id).ok_or(ContractError::PollNotFound)?;
+                    if poll.ballot.is_some() {
+                        return Err(ContractError::BallotQuestion);
+                    }
+                    self.check_proposal_ended(poll)?;
+                    closed.push(*poll_id);
+                }
+                AdminAction::CancelPoll { poll_id, reason } => {
+                    let poll = self.polls.get(poll_id).ok_or(ContractError::PollNotFound)?;
+                    if poll.ballot.is_some() {
+                        return Err(ContractError::BallotQuestion);
+                    }
+                    if cancelled.iter().any(|(id, _)| id == poll_id) {
+                        return Err(ContractError::PollCancelled);
+                    }
+                    self.check_proposal_ended(poll)?;
+                    check_cancellation(poll, reason)?;
+                    cancelled.push((*poll_id, reason));
+                }
+                AdminAction::GrantRole { role, account } => {
+                    access.grant(*role, account.clone());
+                }
+                AdminAction::RevokeRole { role, account } => {
+                    access.revoke(*role, account);
+                }
+                AdminAction::SetConfig(param) => config.apply(param)?,
+                AdminAction::SetCouncil { members, threshold } => {
+                    council = Some(Council::new(members.clone(), *threshold)?);
+                }
+                AdminAction::TransferOwnership { new_owner } => {
+                    pending_owner = Some(new_owner.clone());
+                    ownership_events.push(Event::OwnershipTransferProposed {
+                        owner: self.owner.clone(),
+                        proposed_owner: new_owner.clone(),
+                    });
+                }
+                AdminAction::CancelOwnershipTransfer => {
+                    let proposed_owner = pending_owner.take().ok_or(ContractError::NoPendingTransfer)?;
+                    ownership_events.push(Event::OwnershipTransferCancelled {
+                        owner: self.owner.clone(),
+                        proposed_owner,
+                    });
+                }
+                AdminAction::Callback { name, payload } => {
+                    let callback = self.callbacks.get(name).ok_or(ContractError::CallbackNotFound)?;
+                    calls.push((callback, payload));
+                }
+                AdminAction::CreateProposal { title, description, actions } => {
+                    self.validate_proposal(actions)?;
+                    proposals.push((title, description, actions));
+                }
+                AdminAction::EnableCallback { name } => {
+                    if !self.staged_callbacks.contains_key(name) || enabled.contains(&name) {
+                        return Err(ContractError::CallbackNotFound);
+                    }
+                    enabled.push(name);
+                }
+                AdminAction::SetBalance { wallet_address, balance } => {
+                    self.check_balance(wallet_address, *balance)?;
+                    balances.push((wallet_address, *balance));
+                }
+            }
+        }
+        
+        // A callback that fails to prepare drops the effects prepared so far
+        let effects = calls
+            .into_iter()
+            .map(|(callback, payload)| callback(payload))
+            .collect::<Result<Vec<_>, _>>()?;
+        
+        for poll_id in closed {
+            self.polls.get_mut(&poll_id).unwrap().active = false;
+        }
+        for (poll_id, reason) in cancelled {
+            self.void_poll(poll_id, proposer, reason);
+        }
+        self.access = access;
+        self.config = config;
+        self.council = council;
+        for name in enabled {
+            let callback = self.staged_callbacks.remove(name).unwrap();
+            self.callbacks.insert(name.clone(), callback);
+        }
+        for (wallet_address, balance) in balances {
+            self.balances.insert(wallet_address.clone(), balance);
+        }
+        for (title, description, actions) in proposals {
+            self.open_proposal(proposer.to_string(), title.clone(), description.clone(), actions.clone());
+        }
+        self.pending_owner = pending_owner;
+        self.events.extend(ownership_events);
+        for effect in effects {
+            effect();
+        }
+        
+        Ok(())
+    }
+    
+    // Single authorization check used by every guarded method
+    fn authorize(&self, caller: &str, permission: Permission) -> Result<(), ContractError> {
+        if caller != self.owner && !self.access.is_authorized(caller, permission) {
+            return Err(ContractError::Unauthorized);
+        }
+        
+        // With a council in place, no single key may act alone
+        if self.council.is_some() && permission.requires_council() {
+            return Err(ContractError::CouncilApprovalRequired);
+        }
+        
+        Ok(())
+    }
+    
+    // Get the Merkle root over a poll's votes
+    pub fn get_vote_root(&self, poll_id: u64) -> Result<Hash, ContractError> {
+        if !self.polls.contains_key(&poll_id) {
+            return Err(ContractError::PollNotFound);
+        }
+        
+        Ok(self.vote_root(poll_id))
+    }
+    
+    // Get the global Merkle root over all polls and their vote roots
+    pub fn get_state_root(&self) -> Hash {
+        self.state_tree().1.root()
+    }
+    
+    // Build a proof that a wallet's vote is included in the current state root
+    pub fn prove_vote(&self, poll_id: u64, wallet_address: &str) -> Result<VoteInclusionProof, ContractError> {
+        let poll = self.get_poll(poll_id)?;
+        
+        let (poll_ids, state_tree) = self.state_tree();
+        let poll_index = poll_ids.iter().position(|id| *id == poll_id).unwrap();
+        
+        self.build_vote_proof(
+            poll_id,
+            wallet_address,
+            merkle::poll_header_hash(poll),
+            state_tree.proof(poll_index).unwrap(),
+        )
+    }
+    
+    // Build a proof that a wallet's vote is included in the state root
+    // recorded when the poll was finalized, the one its tally commitment
+    // anchors
+    pub fn prove_vote_at_finalization(
+        &self,
+        poll_id: u64,
+        wallet_address: &str,
+    ) -> Result<VoteInclusionProof, ContractError> {
+        self.get_poll(poll_id)?;
+        
+        let finalized = match self.finalized_states.get(&poll_id) {
+            Some(finalized) => finalized,
+            None => return Err(ContractError::PollNotFinalized),
+        };
+        
+        self.build_vote_proof(
+            poll_id,
+            wallet_address,
+            finalized.poll_header_hash,
+            finalized.poll_proof.clone(),
+        )
+    }
+    
+    // Prove a vote under the poll's vote root and attach the poll's path to
+    // a state root
+    fn build_vote_proof(
+        &self,
+        poll_id: u64,
+        wallet_address: &str,
+        poll_header_hash: Hash,
+        poll_proof: MerkleProof,
+    ) -> Result<VoteInclusionProof, ContractError> {
+        let (entries, vote_tree) = self.vote_tree(poll_id);
+        let vote_index = match entries.iter().position(|(wallet, _)| *wallet == wallet_address) {
+            Some(index) => index,
+            None => return Err(ContractError::VoteNotFound),
+        };
+        
+        Ok(VoteInclusionProof {
+            poll_id,
+            wallet_address: wallet_address.to_string(),
+            choice: entries[vote_index].1.clone(),
+            vote_root: vote_tree.root(),
+            vote_proof: vote_tree.proof(vote_index).unwrap(),
+            poll_header_hash,
+            poll_proof,
+        })
+    }
+    
+    // Root over a poll's votes, built only when a vote changed it since the
+    // last lookup
+    fn vote_root(&self, poll_id: u64) -> Hash {
+        if let Some(root) = self.vote_roots.borrow().get(&poll_id) {
+            return *root;
+        }
+        
+        let root = self.vote_tree(poll_id).1.root();
+        self.vote_roots.borrow_mut().insert(poll_id, root);
+        root
+    }
+    
+    // Tree over a poll's votes, with leaves sorted by wallet address
+    fn vote_tree(&self, poll_id: u64) -> (Vec<(&str, &VoteChoice)>, MerkleTree) {
+        let mut entries: Vec<(&str, &VoteChoice)> = match self.votes.get(&poll_id) {
+            Some(votes) => votes.iter().map(|(wallet, choice)| (wallet.as_str(), choice)).collect(),
+            None => Vec::new(),
+        };
+        entries.sort_by_key(|(wallet, _)| *wallet);
+        
+        let leaves = entries
+            .iter()
+            .map(|(wallet, choice)| merkle::vote_leaf(wallet, choice))
+            .collect();
+        
+        (entries, MerkleTree::new(leaves))
+    }
+    
+    // Tree over all polls, with leaves sorted by poll ID
+    fn state_tree(&self) -> (Vec<u64>, MerkleTree) {
+        let mut poll_ids: Vec<u64> = self.polls.keys().copied().collect();
+        poll_ids.sort();
+        
+        let leaves = poll_ids
+            .iter()
+            .map(|id| {
+                let header = merkle::poll_header_hash(&self.polls[id]);
+                merkle::poll_leaf(*id, &header, &self.vote_root(*id))
+            })
+            .collect();
+        
+        (poll_ids, MerkleTree::new(leaves))
+    }
+}
+
+// Check a poll can still be cancelled, and the reason given for it
+fn check_cancellation(poll: &Poll, reason: &str) -> Result<(), ContractError> {
+    if poll.is_cancelled() {
+        return Err(ContractError::PollCancelled);
+    }
+    
+    if poll.finalized {
+        return Err(ContractError::PollAlreadyFinalized);
+    }
+    
+    if reason.trim().is_empty() || reason.len() > models::MAX_REASON_LEN {
+        return Err(ContractError::InvalidReason);
+    }
+    
+    Ok(())
+}
+
+// Look up a funding proposal that has not passed yet
+fn open_proposal(pool: &ConvictionPool, proposal_id: u32) -> Result<&FundingProposal, ContractError> {
+    let proposal = pool
+        .proposals
+        .get(proposal_id as usize)
+        .ok_or(ContractError::FundingProposalNotFound)?;
+    
+    if proposal.passed_at.is_some() {
+        return Err(ContractError::ProposalClosed);
+    }
+    
+    Ok(proposal)
+}
+
+// Check method parameters against the number of options
+fn validate_method(method: &PollMethod, option_count: usize) -> Result<(), ContractError> {
+    let valid = match method {
+        PollMethod::Plurality | PollMethod::Ranked { .. } | PollMethod::Star => true,
+        PollMethod::Score { min, max } => min < max,
+        PollMethod::Positional { rule } => rule.is_valid(),
+        PollMethod::Stv { seats } => *seats > 0 && (*seats as usize) < option_count,
+        PollMethod::Cumulative(config) => config.is_valid(),
+        PollMethod::Approval { max_choices } => *max_choices > 0 && (*max_choices as usize) <= option_count,
+        PollMethod::MajorityJudgment { grades } => {
+            let unique: BTreeSet<String> = grades.iter().map(|grade| models::normalize_label(grade)).collect();
+            grades.len() >= 2
+                && unique.len() == grades.len()
+                && grades
+                    .iter()
+                    .all(|grade| !grade.trim().is_empty() && grade.len() <= models::MAX_LABEL_LEN)
+        }
+    };
+    
+    if !valid {
+        return Err(ContractError::InvalidSettings);
+    }
+    
+    Ok(())
+}
@@ -0,0 +1,166 @@
+// Conviction voting: continuous funding pools where staked weight builds
+// conviction over time
+use std::collections::BTreeMap;
+
+use crate::results::BASIS_POINTS;
+
+// Fixed-point scale for decay factors: 1.0 is DECAY_SCALE units
+pub const DECAY_SCALE: u128 = 1_000_000_000_000_000_000;
+
+// Binary digits of precision for the fractional part of a half-life
+const DECAY_BITS: u32 = 32;
+
+// Parameters of a conviction pool
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConvictionConfig {
+    // Seconds for a proposal's conviction to close half the gap to its stake
+    pub half_life: u64,
+    // Funds the pool starts with
+    pub funds: u64,
+    // Largest share of the available funds a proposal can ever win, in basis
+    // points; requests at or above it never pass
+    pub max_request_bps: u32,
+    // Scale of every threshold, in basis points. A near-zero request needs
+    // min_threshold_bps * (10000 / max_request_bps)^2 basis points of all
+    // weight, which is min_threshold_bps itself only when max_request_bps
+    // is 10000.
+    pub min_threshold_bps: u32,
+    // Weight each wallet may stake across the pool's proposals
+    pub weights: BTreeMap<String, u64>,
+}
+
+impl ConvictionConfig {
+    // Check every parameter is in range and total weight cannot overflow
+    pub fn is_valid(&self) -> bool {
+        self.half_life > 0
+            && self.funds > 0
+            && self.max_request_bps > 0
+            && self.max_request_bps <= BASIS_POINTS
+            && self.min_threshold_bps > 0
+            && !self.weights.is_empty()
+            && self.weights.values().all(|weight| *weight > 0)
+            && self.weights.values().try_fold(0u64, |total, weight| total.checked_add(*weight)).is_some()
+    }
+
+    // Sum of all wallets' weights
+    pub fn total_weight(&self) -> u64 {
+        self.weights.values().sum()
+    }
+}
+
+// A request for funds from a conviction pool
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FundingProposal {
+    pub id: u32,
+    pub title: String,
+    pub proposer: String,
+    // Wallet that receives the funds if the proposal passes
+    pub beneficiary: String,
+    pub requested: u64,
+    // Weight currently staked on the proposal
+    pub staked: u64,
+    // Conviction as of `updated_at`
+    pub conviction: u64,
+    // Unix timestamp of the last stake change
+    pub updated_at: u64,
+    // Unix timestamp when the proposal passed and was paid out
+    pub passed_at: Option<u64>,
+}
+
+impl FundingProposal {
+    // Conviction at a later time: the gap to the current stake halves every
+    // `half_life` seconds
+    pub fn conviction_at(&self, now: u64, half_life: u64) -> u64 {
+        let factor = decay(now.saturating_sub(self.updated_at), half_life);
+        let gap = self.conviction.abs_diff(self.staked) as u128 * factor / DECAY_SCALE;
+
+        if self.conviction >= self.staked {
+            self.staked + gap as u64
+        } else {
+            self.staked - gap as u64
+        }
+    }
+
+    // Bring conviction up to date before the stake changes
+    pub fn accrue(&mut self, now: u64, half_life: u64) {
+        self.conviction = self.conviction_at(now, half_life);
+        self.updated_at = self.updated_at.max(now);
+    }
+}
+
+// A long-running pool that funds proposals once enough conviction builds up
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConvictionPool {
+    pub id: u64,
+    pub title: String,
+    pub description: String,
+    pub creator: String,
+    pub config: ConvictionConfig,
+    // Funds not yet paid out
+    pub available_funds: u64,
+    pub proposals: Vec<FundingProposal>,
+    // Weight each wallet has staked on each open proposal
+    pub stakes: BTreeMap<String, BTreeMap<u32, u64>>,
+    pub active: bool,
+}
+
+impl ConvictionPool {
+    // Conviction a request needs to pass, or None if it can never pass with
+    // the funds available: min_threshold * total_weight / (max_request - share)^2,
+    // rounded up, with share = requested / available_funds
+    pub fn threshold(&self, requested: u64) -> Option<u64> {
+        if self.available_funds == 0 || requested > self.available_funds {
+            return None;
+        }
+
+        let scale = BASIS_POINTS as u128;
+        let share_bps = requested as u128 * scale / self.available_funds as u128;
+        let headroom = (self.config.max_request_bps as u128).checked_sub(share_bps).filter(|h| *h > 0)?;
+
+        let numerator = self.config.total_weight() as u128 * self.config.min_threshold_bps as u128 * scale;
+        let threshold = numerator.div_ceil(headroom * headroom);
+        Some(u64::try_from(threshold).unwrap_or(u64::MAX))
+    }
+
+    // Weight a wallet has staked across all open proposals
+    pub fn staked_by(&self, wallet_address: &str) -> u64 {
+        self.stakes.get(wallet_address).map_or(0, |stakes| stakes.values().sum())
+    }
+}
+
+// A proposal's standing at a point in time
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConvictionStatus {
+    pub staked: u64,
+    pub conviction: u64,
+    // None when the request can never pass with the funds available
+    pub threshold: Option<u64>,
+    // Whether `execute_funding_proposal` would succeed
+    pub passable: bool,
+}
+
+// Share of a gap left after `elapsed` seconds, in DECAY_SCALE units:
+// 2^(-elapsed / half_life), using integer square roots for the fraction
+pub fn decay(elapsed: u64, half_life: u64) -> u128 {
+    let halvings = elapsed / half_life;
+    if halvings >= 64 {
+        return 0;
+    }
+
+    let mut factor = DECAY_SCALE >> halvings;
+    let half_life = half_life as u128;
+    let mut remainder = (elapsed as u128) % half_life;
+
+    // Each set binary digit k of remainder / half_life multiplies by 2^(-1/2^k)
+    let mut root = (DECAY_SCALE / 2 * DECAY_SCALE).isqrt();
+    for _ in 0..DECAY_BITS {
+        remainder *= 2;
+        if remainder >= half_life {
+            remainder -= half_life;
+            factor = factor * root / DECAY_SCALE;
+        }
+        root = (root * DECAY_SCALE).isqrt();
+    }
+
+    factor
+}
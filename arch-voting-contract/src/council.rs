@@ -0,0 +1,73 @@
+// M-of-N admin council for privileged operations
+use crate::access::Role;
+use crate::config::ConfigParam;
+use crate::errors::ContractError;
+
+// Privileged operations that require council approval once a council exists
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AdminAction {
+    ClosePoll { poll_id: u64 },
+    // Void a poll as `cancel_poll` does, recording the proposer as canceller
+    CancelPoll { poll_id: u64, reason: String },
+    GrantRole { role: Role, account: String },
+    RevokeRole { role: Role, account: String },
+    SetConfig(ConfigParam),
+    SetCouncil { members: Vec<String>, threshold: u32 },
+    // Nominate `new_owner`, who becomes owner by calling `accept_ownership`
+    TransferOwnership { new_owner: String },
+    // Withdraw the pending nomination, as `cancel_ownership_transfer` does
+    CancelOwnershipTransfer,
+    // Call the callback registered under `name` with `payload`
+    Callback { name: String, payload: Vec<u8> },
+    // Put `actions` to a vote as an executable proposal, created by the
+    // action's proposer
+    CreateProposal { title: String, description: String, actions: Vec<AdminAction> },
+    // Make a callback staged by `register_callback` callable
+    EnableCallback { name: String },
+    // Set the balance a wallet may lock, as `set_balance` does
+    SetBalance { wallet_address: String, balance: u64 },
+}
+
+// Council members and the number of approvals an action needs
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Council {
+    pub members: Vec<String>,
+    pub threshold: u32,
+}
+
+impl Council {
+    // Validate membership and threshold
+    pub fn new(members: Vec<String>, threshold: u32) -> Result<Self, ContractError> {
+        let mut unique = members.clone();
+        unique.sort();
+        unique.dedup();
+
+        if unique.len() != members.len() || members.iter().any(|m| m.is_empty()) {
+            return Err(ContractError::InvalidCouncil);
+        }
+
+        if threshold == 0 || threshold as usize > members.len() {
+            return Err(ContractError::InvalidCouncil);
+        }
+
+        Ok(Council { members, threshold })
+    }
+
+    // Check if an address sits on the council
+    pub fn is_member(&self, account: &str) -> bool {
+        self.members.iter().any(|m| m == account)
+    }
+}
+
+// A pending or executed council action
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AdminProposal {
+    pub id: u64,
+    pub action: AdminAction,
+    pub proposer: String,
+    // Members who approved, in approval order (the proposer comes first)
+    pub approvals: Vec<String>,
+    // Unix timestamp after which approvals are no longer accepted
+    pub deadline: u64,
+    pub executed: bool,
+}
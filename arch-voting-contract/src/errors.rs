@@ -10,4 +10,48 @@ pub enum ContractError {
     InvalidOption,      // When option index is out of bounds
     AlreadyVoted,       // When wallet has already voted
     InvalidTimeRange,   // When start_time >= end_time
+    VoteNotFound,       // When a wallet has no vote recorded in the poll
+    PollNotFinalized,   // When results are requested before finalization
+    PollAlreadyFinalized, // When finalizing a poll twice
+    InvalidPayload,     // When an anchor payload cannot be decoded
+    CommitmentMismatch, // When an anchor payload disagrees with contract state
+    NoPendingTransfer,  // When no ownership transfer is awaiting acceptance
+    InvalidConfig,      // When a configuration value is out of range
+    InvalidCouncil,     // When council members or threshold are invalid
+    NoCouncil,          // When a council action is attempted without a council
+    CouncilApprovalRequired, // When a privileged action must go through the council
+    ProposalNotFound,   // When referenced council or executable proposal doesn't exist
+    ProposalExpired,    // When approving after the approval deadline
+    ProposalAlreadyExecuted, // When approving or executing actions that already ran
+    AlreadyApproved,    // When a member approves the same proposal twice
+    DuplicateOption,    // When two option labels are equal after normalization
+    InvalidSettings,    // When poll settings are out of range
+    NotEligible,        // When a wallet is not on the poll's voter list
+    BallotNotFound,     // When referenced ballot doesn't exist
+    BallotQuestion,     // When voting on, editing, closing or cancelling a ballot's question poll directly
+    OptionTooLarge,     // When an option label or its metadata exceeds size limits
+    EmptyLabel,         // When an option label is blank
+    PollAlreadyStarted, // When editing a poll after voting has started
+    PollCancelled,      // When a poll has been voided by cancel_poll
+    InvalidReason,      // When a cancellation reason is empty or too long
+    MethodMismatch,     // When a ballot's form doesn't match the poll's voting method
+    ScoreOutOfRange,    // When a score lies outside the poll's range
+    OverBudget,         // When a cumulative ballot spends more points than the voter has
+    UnderBudget,        // When a cumulative ballot must spend the whole budget but doesn't
+    TallyOverflow,      // When counting a ballot would overflow an option's tally
+    PoolNotFound,       // When referenced conviction pool doesn't exist
+    FundingProposalNotFound, // When referenced funding proposal doesn't exist
+    InsufficientWeight, // When staking more weight than is free or unstaking more than is staked
+    InsufficientFunds,  // When a funding request exceeds the pool's available funds
+    ThresholdNotReached, // When a funding proposal's conviction is below its threshold
+    ProposalClosed,     // When a funding proposal has already passed
+    InvalidLock,        // When a lock amount or unlock time is out of range
+    LockNotFound,       // When a wallet has no active lock
+    LockExists,         // When creating a lock for a wallet that already has one
+    LockNotExpired,     // When withdrawing before the unlock time
+    NoVotingPower,      // When a wallet has no voting power at the poll's snapshot
+    CallbackNotFound,   // When an action calls a callback that isn't registered
+    ProposalRejected,   // When executing a proposal whose poll did not pass
+    SystemClock,        // When setting the time of a contract that reads the system clock
+    InsufficientBalance, // When locking more than the wallet's free balance, or setting a balance below its lock
 }
\ No newline at end of file
@@ -0,0 +1,56 @@
+// Vote-escrow locks: voting power from balances locked for a chosen time
+
+// A balance locked until `unlock_time`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Lock {
+    pub amount: u64,
+    // Unix timestamp when the balance can be withdrawn
+    pub unlock_time: u64,
+    // Lock duration that earns full power, as configured when the lock was
+    // last changed
+    pub max_duration: u64,
+}
+
+impl Lock {
+    // Voting power at a timestamp: `amount` scaled by the remaining lock time
+    // over `max_duration`, falling linearly to zero at `unlock_time`
+    pub fn power_at(&self, timestamp: u64) -> u64 {
+        let remaining = self.unlock_time.saturating_sub(timestamp).min(self.max_duration);
+        (self.amount as u128 * remaining as u128 / self.max_duration as u128) as u64
+    }
+}
+
+// Every state a wallet's lock has been in, so power can be read at any
+// earlier time
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LockHistory {
+    // (timestamp, lock) in time order; None once the balance is withdrawn
+    pub checkpoints: Vec<(u64, Option<Lock>)>,
+}
+
+impl LockHistory {
+    // The lock as it stood at a timestamp, including changes made during
+    // that second
+    pub fn at(&self, timestamp: u64) -> Option<&Lock> {
+        let index = self.checkpoints.partition_point(|(changed_at, _)| *changed_at <= timestamp);
+        index.checked_sub(1).and_then(|index| self.checkpoints[index].1.as_ref())
+    }
+
+    // The lock as it stands now
+    pub fn current(&self) -> Option<&Lock> {
+        self.checkpoints.last().and_then(|(_, lock)| lock.as_ref())
+    }
+
+    // Voting power at a timestamp
+    pub fn power_at(&self, timestamp: u64) -> u64 {
+        self.at(timestamp).map_or(0, |lock| lock.power_at(timestamp))
+    }
+
+    // Record a new state; a change in the same second replaces the last one
+    pub fn push(&mut self, now: u64, lock: Option<Lock>) {
+        match self.checkpoints.last_mut() {
+            Some((changed_at, last)) if *changed_at == now => *last = lock,
+            _ => self.checkpoints.push((now, lock)),
+        }
+    }
+}
@@ -0,0 +1,61 @@
+// Events emitted by the voting contract
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Event {
+    // The owner nominated a new owner who has not yet accepted
+    OwnershipTransferProposed {
+        owner: String,
+        proposed_owner: String,
+    },
+    // The nominee accepted and is now the owner
+    OwnershipTransferred {
+        previous_owner: String,
+        new_owner: String,
+    },
+    // The owner withdrew a pending nomination
+    OwnershipTransferCancelled {
+        owner: String,
+        proposed_owner: String,
+    },
+    // A council member proposed a privileged action
+    AdminActionProposed {
+        proposal_id: u64,
+        proposer: String,
+    },
+    // A council member approved a proposal
+    AdminActionApproved {
+        proposal_id: u64,
+        approver: String,
+    },
+    // A proposal reached its threshold and its action ran
+    AdminActionExecuted {
+        proposal_id: u64,
+    },
+    // A poll's details were edited before voting started
+    PollUpdated {
+        poll_id: u64,
+        editor: String,
+    },
+    // A poll was voided and its results should not be used
+    PollCancelled {
+        poll_id: u64,
+        cancelled_by: String,
+        reason: String,
+    },
+    // A funding proposal's conviction reached its threshold and it was paid out
+    FundingProposalPassed {
+        pool_id: u64,
+        proposal_id: u32,
+        beneficiary: String,
+        amount: u64,
+    },
+    // A passed proposal's actions all ran
+    ProposalExecuted {
+        poll_id: u64,
+    },
+    // A passed proposal's actions failed at finalization and were rolled
+    // back; `execute_proposal` can retry them
+    ProposalExecutionFailed {
+        poll_id: u64,
+    },
+}
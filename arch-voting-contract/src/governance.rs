@@ -0,0 +1,27 @@
+// Executable governance proposals: polls that carry actions to run if they pass
+use crate::council::AdminAction;
+use crate::errors::ContractError;
+
+// Option labels of every executable proposal's poll, in index order
+pub const PROPOSAL_OPTIONS: [&str; 2] = ["Approve", "Reject"];
+
+// Code registered under a name that a passed proposal can call with a
+// payload. It only checks the payload and prepares its effect; the contract
+// applies the effect once every action of the proposal has succeeded.
+pub type Callback = Box<dyn Fn(&[u8]) -> Result<CallbackEffect, ContractError>>;
+
+// Prepared effect of a callback; it runs at most once and cannot fail
+pub type CallbackEffect = Box<dyn FnOnce()>;
+
+// Actions attached to a poll, run once after it passes finalization
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExecutableProposal {
+    pub poll_id: u64,
+    // Run in order, all or nothing
+    pub actions: Vec<AdminAction>,
+    // Approve and Reject votes needed for the proposal to pass, fixed from
+    // the configured quorum and electorate at creation
+    pub quorum: u64,
+    // Unix timestamp when the actions ran
+    pub executed_at: Option<u64>,
+}
@@ -0,0 +1,111 @@
+// Majority judgment: rank options by median grade
+use std::cmp::Ordering;
+
+use crate::models::{Poll, VoteResults};
+
+// Grading results of a majority judgment poll
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MajorityJudgmentResults {
+    pub poll_id: u64,
+    // Grade labels from worst to best
+    pub scale: Vec<String>,
+    // distributions[i][g]: voters who gave option i grade g
+    pub distributions: Vec<Vec<u64>>,
+    // Lower median grade of each option; None before anyone has voted
+    pub medians: Vec<Option<u32>>,
+    // Voters who graded each option above its median
+    pub above_median: Vec<u64>,
+    // Voters who graded each option below its median
+    pub below_median: Vec<u64>,
+    // Option indices from best to worst, ties in index order
+    pub ranking: Vec<u32>,
+    // Options sharing the best majority value; more than one means a tie
+    pub winners: Vec<u32>,
+}
+
+impl MajorityJudgmentResults {
+    // Rank the poll's options from their grade distributions
+    pub fn new(poll: &Poll, results: &VoteResults, scale: &[String]) -> Self {
+        let distributions = results.grades.clone();
+
+        let medians: Vec<Option<u32>> = distributions.iter().map(|d| lower_median(d)).collect();
+        let above_median = distributions
+            .iter()
+            .zip(&medians)
+            .map(|(d, median)| match median {
+                Some(median) => d[*median as usize + 1..].iter().sum(),
+                None => 0,
+            })
+            .collect();
+        let below_median = distributions
+            .iter()
+            .zip(&medians)
+            .map(|(d, median)| match median {
+                Some(median) => d[..*median as usize].iter().sum(),
+                None => 0,
+            })
+            .collect();
+
+        let mut ranking: Vec<u32> = (0..distributions.len() as u32).collect();
+        ranking.sort_by(|a, b| compare(&distributions[*b as usize], &distributions[*a as usize]));
+
+        let winners = match ranking.first() {
+            Some(best) => ranking
+                .iter()
+                .copied()
+                .filter(|index| {
+                    compare(&distributions[*index as usize], &distributions[*best as usize]) == Ordering::Equal
+                })
+                .collect(),
+            None => Vec::new(),
+        };
+
+        MajorityJudgmentResults {
+            poll_id: poll.id,
+            scale: scale.to_vec(),
+            distributions,
+            medians,
+            above_median,
+            below_median,
+            ranking,
+            winners,
+        }
+    }
+}
+
+// Lower median grade of a distribution, or None if it is empty
+fn lower_median(distribution: &[u64]) -> Option<u32> {
+    let total: u64 = distribution.iter().sum();
+    if total == 0 {
+        return None;
+    }
+
+    // 0-based position of the lower median in ascending order
+    let target = (total - 1) / 2;
+    let mut seen = 0;
+    for (grade, voters) in distribution.iter().enumerate() {
+        seen += voters;
+        if seen > target {
+            return Some(grade as u32);
+        }
+    }
+
+    None
+}
+
+// Compare two options by majority value: compare their medians, and while
+// they are equal remove one median grade from each and compare again
+fn compare(a: &[u64], b: &[u64]) -> Ordering {
+    let mut a = a.to_vec();
+    let mut b = b.to_vec();
+
+    loop {
+        match (lower_median(&a), lower_median(&b)) {
+            (Some(median_a), Some(median_b)) if median_a == median_b => {
+                a[median_a as usize] -= 1;
+                b[median_b as usize] -= 1;
+            }
+            (median_a, median_b) => return median_a.cmp(&median_b),
+        }
+    }
+}
@@ -1,10 +1,52 @@
 // Main entry point for the voting contract library
 
 // Re-exports
+pub mod access;
+pub mod anchor;
+pub mod ballot;
+pub mod clock;
+pub mod condorcet;
+pub mod config;
 pub mod contract;
+pub mod conviction;
+pub mod council;
 pub mod models;
 pub mod errors;
+pub mod escrow;
+pub mod events;
+pub mod governance;
+pub mod judgment;
+pub mod merkle;
+pub mod positional;
+pub mod query;
+pub mod results;
+pub mod star;
+pub mod stv;
 
+pub use access::{Permission, Role};
+pub use anchor::TallyCommitment;
+pub use ballot::{Answer, Ballot, BallotResults, Question, QuestionResults, VotingMethod};
+pub use clock::Clock;
+pub use condorcet::{CondorcetResults, CondorcetRule};
+pub use config::{ConfigParam, ContractConfig};
+pub use council::{AdminAction, AdminProposal, Council};
 pub use contract::VotingContract;
-pub use models::{Poll, VoteResults};
-pub use errors::ContractError;
\ No newline at end of file
+pub use conviction::{ConvictionConfig, ConvictionPool, ConvictionStatus, FundingProposal};
+pub use models::{
+    Cancellation, CumulativeConfig, Poll, PollEdit, PollMethod, PollOption, PollSettings,
+    PollStatus, PollUpdate, RunoffConfig, ScoreTally, TieBreakPolicy, VoteChoice, VoteResults,
+    VotingPower,
+};
+pub use errors::ContractError;
+pub use escrow::{Lock, LockHistory};
+pub use events::Event;
+pub use governance::{Callback, CallbackEffect, ExecutableProposal};
+pub use judgment::MajorityJudgmentResults;
+pub use merkle::{Hash, MerkleProof, VoteInclusionProof};
+pub use positional::{PositionalResults, PositionalRule};
+pub use results::{OptionResult, OrderedResults, PollOutcome, RoundingMode};
+pub use star::StarResults;
+pub use stv::{StvAction, StvResults, StvRound};
+pub use query::{
+    PageRequest, PollCursor, PollPage, PollQuery, PollSortKey, WalletVote, WalletVotesPage,
+};
\ No newline at end of file
@@ -0,0 +1,500 @@
+// Merkle commitments over poll votes and contract state
+use sha2::{Digest, Sha256};
+
+use crate::condorcet::CondorcetRule;
+use crate::models::{
+    Poll, PollMethod, PollOption, PollSettings, PollUpdate, TieBreakPolicy, VoteChoice, VoteResults, VotingPower,
+};
+use crate::positional::PositionalRule;
+
+// A 32-byte SHA-256 digest
+pub type Hash = [u8; 32];
+
+// Root of a tree with no leaves
+pub const EMPTY_ROOT: Hash = [0u8; 32];
+
+// Domain separation prefixes so a leaf can never be passed off as an inner node
+const LEAF_PREFIX: u8 = 0x00;
+const NODE_PREFIX: u8 = 0x01;
+
+// Hash arbitrary bytes with SHA-256
+pub fn hash_bytes(data: &[u8]) -> Hash {
+    Sha256::digest(data).into()
+}
+
+// Hash two child nodes into their parent
+pub fn hash_node(left: &Hash, right: &Hash) -> Hash {
+    let mut hasher = Sha256::new();
+    hasher.update([NODE_PREFIX]);
+    hasher.update(left);
+    hasher.update(right);
+    hasher.finalize().into()
+}
+
+// Leaf committing to a single wallet's vote. A single choice is encoded as
+// just its option index; other ballots start with a tag byte, so the
+// encodings can never collide.
+pub fn vote_leaf(wallet_address: &str, choice: &VoteChoice) -> Hash {
+    let mut hasher = Sha256::new();
+    hasher.update([LEAF_PREFIX]);
+    hasher.update((wallet_address.len() as u32).to_be_bytes());
+    hasher.update(wallet_address.as_bytes());
+    match choice {
+        VoteChoice::Single(option_index) => hasher.update(option_index.to_be_bytes()),
+        VoteChoice::Scores(scores) => {
+            hasher.update([1]);
+            hasher.update((scores.len() as u32).to_be_bytes());
+            for score in scores {
+                match score {
+                    Some(score) => {
+                        hasher.update([1]);
+                        hasher.update(score.to_be_bytes());
+                    }
+                    None => hasher.update([0]),
+                }
+            }
+        }
+        VoteChoice::Ranking(ranking) => {
+            hasher.update([2]);
+            hasher.update((ranking.len() as u32).to_be_bytes());
+            for option_index in ranking {
+                hasher.update(option_index.to_be_bytes());
+            }
+        }
+        VoteChoice::Points(points) => {
+            hasher.update([3]);
+            hasher.update((points.len() as u32).to_be_bytes());
+            for points in points {
+                hasher.update(points.to_be_bytes());
+            }
+        }
+        VoteChoice::Grades(grades) => {
+            hasher.update([4]);
+            hasher.update((grades.len() as u32).to_be_bytes());
+            for grade in grades {
+                hasher.update(grade.to_be_bytes());
+            }
+        }
+        VoteChoice::Approvals(indices) => {
+            hasher.update([5]);
+            hasher.update((indices.len() as u32).to_be_bytes());
+            for option_index in indices {
+                hasher.update(option_index.to_be_bytes());
+            }
+        }
+    }
+    hasher.finalize().into()
+}
+
+// Digest of a poll's descriptive fields (everything except its votes).
+// Every variable-length field is length-prefixed and every optional or enum
+// field starts with a tag byte, so two different polls never share a digest.
+pub fn poll_header_hash(poll: &Poll) -> Hash {
+    let mut hasher = Sha256::new();
+    hasher.update(poll.id.to_be_bytes());
+    for field in [&poll.title, &poll.description, &poll.creator] {
+        update_str(&mut hasher, field);
+    }
+    update_options(&mut hasher, &poll.options);
+    hasher.update(poll.start_time.to_be_bytes());
+    hasher.update(poll.end_time.to_be_bytes());
+    hasher.update([poll.active as u8]);
+    hasher.update([poll.finalized as u8]);
+    hasher.update((poll.tags.len() as u32).to_be_bytes());
+    for tag in &poll.tags {
+        update_str(&mut hasher, tag);
+    }
+    update_settings(&mut hasher, &poll.settings);
+    for link in [poll.parent_poll, poll.runoff_poll, poll.ballot] {
+        match link {
+            Some(id) => {
+                hasher.update([1]);
+                hasher.update(id.to_be_bytes());
+            }
+            None => hasher.update([0]),
+        }
+    }
+    hasher.update((poll.edit_history.len() as u32).to_be_bytes());
+    for edit in &poll.edit_history {
+        update_str(&mut hasher, &edit.editor);
+        hasher.update(edit.edited_at.to_be_bytes());
+        update_poll_update(&mut hasher, &edit.previous);
+    }
+    match &poll.cancellation {
+        Some(cancellation) => {
+            hasher.update([1]);
+            update_str(&mut hasher, &cancellation.reason);
+            update_str(&mut hasher, &cancellation.cancelled_by);
+            hasher.update(cancellation.cancelled_at.to_be_bytes());
+        }
+        None => hasher.update([0]),
+    }
+    match &poll.draw_seed {
+        Some(seed) => {
+            hasher.update([1]);
+            hasher.update(seed);
+        }
+        None => hasher.update([0]),
+    }
+    hasher.finalize().into()
+}
+
+// Length-prefixed string
+fn update_str(hasher: &mut Sha256, value: &str) {
+    hasher.update((value.len() as u32).to_be_bytes());
+    hasher.update(value.as_bytes());
+}
+
+// Tagged optional string
+fn update_opt_str(hasher: &mut Sha256, value: Option<&str>) {
+    match value {
+        Some(value) => {
+            hasher.update([1]);
+            update_str(hasher, value);
+        }
+        None => hasher.update([0]),
+    }
+}
+
+// Tagged optional integer
+fn update_opt_u64(hasher: &mut Sha256, value: Option<u64>) {
+    match value {
+        Some(value) => {
+            hasher.update([1]);
+            hasher.update(value.to_be_bytes());
+        }
+        None => hasher.update([0]),
+    }
+}
+
+// Options with all their metadata, in index order
+fn update_options(hasher: &mut Sha256, options: &[PollOption]) {
+    hasher.update((options.len() as u32).to_be_bytes());
+    for option in options {
+        update_str(hasher, &option.label);
+        update_str(hasher, &option.description);
+        update_opt_str(hasher, option.uri.as_deref());
+        update_opt_str(hasher, option.proposer.as_deref());
+        match &option.image_hash {
+            Some(hash) => {
+                hasher.update([1]);
+                hasher.update(hash);
+            }
+            None => hasher.update([0]),
+        }
+    }
+}
+
+// The values an edit replaced
+fn update_poll_update(hasher: &mut Sha256, update: &PollUpdate) {
+    update_opt_str(hasher, update.title.as_deref());
+    update_opt_str(hasher, update.description.as_deref());
+    match &update.options {
+        Some(options) => {
+            hasher.update([1]);
+            update_options(hasher, options);
+        }
+        None => hasher.update([0]),
+    }
+    update_opt_u64(hasher, update.start_time);
+    update_opt_u64(hasher, update.end_time);
+}
+
+// Method, voting power, tie-break, runoff and electorate
+fn update_settings(hasher: &mut Sha256, settings: &PollSettings) {
+    match &settings.method {
+        PollMethod::Plurality => hasher.update([0]),
+        PollMethod::Score { min, max } => {
+            hasher.update([1]);
+            hasher.update(min.to_be_bytes());
+            hasher.update(max.to_be_bytes());
+        }
+        PollMethod::Ranked { rule } => {
+            hasher.update([2]);
+            hasher.update([match rule {
+                CondorcetRule::Schulze => 0,
+                CondorcetRule::RankedPairs => 1,
+            }]);
+        }
+        PollMethod::Positional { rule } => {
+            hasher.update([3]);
+            match rule {
+                PositionalRule::Borda => hasher.update([0]),
+                PositionalRule::Dowdall => hasher.update([1]),
+                PositionalRule::Custom(weights) => {
+                    hasher.update([2]);
+                    hasher.update((weights.len() as u32).to_be_bytes());
+                    for weight in weights {
+                        hasher.update(weight.to_be_bytes());
+                    }
+                }
+            }
+        }
+        PollMethod::Stv { seats } => {
+            hasher.update([4]);
+            hasher.update(seats.to_be_bytes());
+        }
+        PollMethod::Cumulative(config) => {
+            hasher.update([5]);
+            hasher.update(config.points.to_be_bytes());
+            hasher.update([config.exact as u8]);
+            match &config.weights {
+                Some(weights) => {
+                    hasher.update([1]);
+                    hasher.update((weights.len() as u32).to_be_bytes());
+                    for (wallet, weight) in weights {
+                        update_str(hasher, wallet);
+                        hasher.update(weight.to_be_bytes());
+                    }
+                }
+                None => hasher.update([0]),
+            }
+        }
+        PollMethod::MajorityJudgment { grades } => {
+            hasher.update([6]);
+            hasher.update((grades.len() as u32).to_be_bytes());
+            for grade in grades {
+                update_str(hasher, grade);
+            }
+        }
+        PollMethod::Star => hasher.update([7]),
+        PollMethod::Approval { max_choices } => {
+            hasher.update([8]);
+            hasher.update(max_choices.to_be_bytes());
+        }
+    }
+    hasher.update([match settings.voting_power {
+        VotingPower::OnePerWallet => 0,
+        VotingPower::Escrow => 1,
+    }]);
+    match &settings.tie_break {
+        TieBreakPolicy::LowestIndex => hasher.update([0]),
+        TieBreakPolicy::EarliestVote => hasher.update([1]),
+        TieBreakPolicy::Runoff { duration } => {
+            hasher.update([2]);
+            hasher.update(duration.to_be_bytes());
+        }
+        TieBreakPolicy::SeededDraw { seed } => {
+            hasher.update([3]);
+            hasher.update(seed);
+        }
+    }
+    match &settings.runoff {
+        Some(runoff) => {
+            hasher.update([1]);
+            hasher.update(runoff.majority_bps.to_be_bytes());
+            hasher.update(runoff.top_n.to_be_bytes());
+            hasher.update(runoff.duration.to_be_bytes());
+        }
+        None => hasher.update([0]),
+    }
+    match &settings.eligible_voters {
+        Some(voters) => {
+            hasher.update([1]);
+            hasher.update((voters.len() as u32).to_be_bytes());
+            for voter in voters {
+                update_str(hasher, voter);
+            }
+        }
+        None => hasher.update([0]),
+    }
+}
+
+// Digest of a poll's whole tally: counts, plus the score, pairwise,
+// position, ranking and grade tallies that decide the other methods'
+// winners. Per-option values are listed in option order.
+pub fn results_hash(poll_id: u64, results: &VoteResults) -> Hash {
+    let mut hasher = Sha256::new();
+    hasher.update(b"results");
+    hasher.update(poll_id.to_be_bytes());
+    let option_count = results.counts.len() as u32;
+    hasher.update(option_count.to_be_bytes());
+    for index in 0..option_count {
+        hasher.update(results.counts[&index].to_be_bytes());
+    }
+    hasher.update(results.total_votes.to_be_bytes());
+    for index in 0..option_count {
+        update_opt_u64(&mut hasher, results.reached_at.get(&index).copied());
+    }
+    hasher.update((results.scores.len() as u32).to_be_bytes());
+    for index in 0..option_count {
+        if let Some(tally) = results.scores.get(&index) {
+            hasher.update(tally.sum.to_be_bytes());
+            hasher.update(tally.scored.to_be_bytes());
+            hasher.update(tally.unscored.to_be_bytes());
+        }
+    }
+    for matrix in [&results.pairwise, &results.positions, &results.grades] {
+        update_matrix(&mut hasher, matrix);
+    }
+    hasher.update((results.rankings.len() as u32).to_be_bytes());
+    for ranking in &results.rankings {
+        hasher.update((ranking.len() as u32).to_be_bytes());
+        for option_index in ranking {
+            hasher.update(option_index.to_be_bytes());
+        }
+    }
+    hasher.finalize().into()
+}
+
+// Length-prefixed rows of a tally matrix
+fn update_matrix(hasher: &mut Sha256, matrix: &[Vec<u64>]) {
+    hasher.update((matrix.len() as u32).to_be_bytes());
+    for row in matrix {
+        hasher.update((row.len() as u32).to_be_bytes());
+        for value in row {
+            hasher.update(value.to_be_bytes());
+        }
+    }
+}
+
+// Leaf committing to a poll inside the global state tree
+pub fn poll_leaf(poll_id: u64, header_hash: &Hash, vote_root: &Hash) -> Hash {
+    let mut hasher = Sha256::new();
+    hasher.update([LEAF_PREFIX]);
+    hasher.update(poll_id.to_be_bytes());
+    hasher.update(header_hash);
+    hasher.update(vote_root);
+    hasher.finalize().into()
+}
+
+// One step of an inclusion proof
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProofNode {
+    pub hash: Hash,
+    // Whether the sibling sits to the left of the running hash
+    pub is_left: bool,
+}
+
+// Path from a leaf up to the root
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MerkleProof {
+    pub leaf_index: u64,
+    pub siblings: Vec<ProofNode>,
+}
+
+impl MerkleProof {
+    // Fold the leaf up through the siblings
+    pub fn compute_root(&self, leaf: Hash) -> Hash {
+        self.siblings.iter().fold(leaf, |acc, node| {
+            if node.is_left {
+                hash_node(&node.hash, &acc)
+            } else {
+                hash_node(&acc, &node.hash)
+            }
+        })
+    }
+
+    // Check that the leaf is committed to by the given root
+    pub fn verify(&self, root: &Hash, leaf: Hash) -> bool {
+        self.compute_root(leaf) == *root
+    }
+}
+
+// Binary Merkle tree. A node without a sibling is carried up unchanged
+// rather than duplicated, so two different leaf sets never share a root.
+pub struct MerkleTree {
+    levels: Vec<Vec<Hash>>,
+}
+
+impl MerkleTree {
+    // Build the tree bottom-up from its leaves
+    pub fn new(leaves: Vec<Hash>) -> Self {
+        let mut levels = vec![leaves];
+
+        while levels.last().unwrap().len() > 1 {
+            let level = levels.last().unwrap();
+            let next = level
+                .chunks(2)
+                .map(|pair| match pair {
+                    [left, right] => hash_node(left, right),
+                    [single] => *single,
+                    _ => unreachable!(),
+                })
+                .collect();
+            levels.push(next);
+        }
+
+        MerkleTree { levels }
+    }
+
+    // Root of the tree, or EMPTY_ROOT when there are no leaves
+    pub fn root(&self) -> Hash {
+        match self.levels.last().and_then(|level| level.first()) {
+            Some(root) => *root,
+            None => EMPTY_ROOT,
+        }
+    }
+
+    // Inclusion proof for the leaf at the given index
+    pub fn proof(&self, leaf_index: usize) -> Option<MerkleProof> {
+        if leaf_index >= self.levels[0].len() {
+            return None;
+        }
+
+        let mut siblings = Vec::new();
+        let mut index = leaf_index;
+
+        for level in &self.levels[..self.levels.len() - 1] {
+            let sibling = index ^ 1;
+            if sibling < level.len() {
+                siblings.push(ProofNode {
+                    hash: level[sibling],
+                    is_left: sibling < index,
+                });
+            }
+            index /= 2;
+        }
+
+        Some(MerkleProof {
+            leaf_index: leaf_index as u64,
+            siblings,
+        })
+    }
+}
+
+// A finalized poll's place in the state tree at finalization. Its votes no
+// longer change, so this is all that is needed to keep proving them against
+// the anchored root after the rest of the state moves on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FinalizedState {
+    pub state_root: Hash,
+    // Digest of the poll's descriptive fields when it was finalized
+    pub poll_header_hash: Hash,
+    // Proof of the poll leaf under state_root
+    pub poll_proof: MerkleProof,
+}
+
+// Everything a voter needs to check their ballot against a published state root
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VoteInclusionProof {
+    pub poll_id: u64,
+    pub wallet_address: String,
+    pub choice: VoteChoice,
+    // Root over the poll's votes map
+    pub vote_root: Hash,
+    // Proof of the vote leaf under vote_root
+    pub vote_proof: MerkleProof,
+    // Digest of the poll's descriptive fields
+    pub poll_header_hash: Hash,
+    // Proof of the poll leaf under the global state root
+    pub poll_proof: MerkleProof,
+}
+
+impl VoteInclusionProof {
+    // Check the vote against the poll's vote root only
+    pub fn verify_vote(&self) -> bool {
+        let leaf = vote_leaf(&self.wallet_address, &self.choice);
+        self.vote_proof.verify(&self.vote_root, leaf)
+    }
+
+    // Check the full path from the vote up to the global state root
+    pub fn verify(&self, state_root: &Hash) -> bool {
+        if !self.verify_vote() {
+            return false;
+        }
+
+        let leaf = poll_leaf(self.poll_id, &self.poll_header_hash, &self.vote_root);
+        self.poll_proof.verify(state_root, leaf)
+    }
+}
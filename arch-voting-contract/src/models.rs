@@ -1,5 +1,13 @@
 // Data models for the voting contract
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use unicode_normalization::UnicodeNormalization;
+
+use crate::condorcet::{self, CondorcetRule};
+use crate::errors::ContractError;
+use crate::positional::PositionalRule;
+use crate::star;
+use crate::merkle::Hash;
 
 // Represents a single poll
 pub struct Poll {
@@ -7,7 +15,7 @@ pub struct Poll {
     pub title: String,
     pub description: String,
     // List of options that users can vote for
-    pub options: Vec<String>,
+    pub options: Vec<PollOption>,
     // Creator of the poll
     pub creator: String,
     // Unix timestamp when voting starts
@@ -16,14 +24,422 @@ pub struct Poll {
     pub end_time: u64,
     // Whether the poll is active
     pub active: bool,
+    // Whether the results have been finalized and can be anchored
+    pub finalized: bool,
+    // Free-form labels used to filter polls
+    pub tags: Vec<String>,
+    // Per-poll behaviour chosen at creation
+    pub settings: PollSettings,
+    // Poll this one is a runoff for, if any
+    pub parent_poll: Option<u64>,
+    // Runoff poll spawned when this one was finalized, if any
+    pub runoff_poll: Option<u64>,
+    // Edits made before voting started, oldest first
+    pub edit_history: Vec<PollEdit>,
+    // Set when the poll was voided by `cancel_poll`
+    pub cancellation: Option<Cancellation>,
+    // Seed for a `SeededDraw` tie-break, fixed at finalization from the
+    // committed seed and the final vote root
+    pub draw_seed: Option<Hash>,
+    // Ballot this poll is a question of, if any; such polls are only voted
+    // on through `cast_ballot`
+    pub ballot: Option<u64>,
+}
+
+// Largest accepted cancellation reason, in bytes
+pub const MAX_REASON_LEN: usize = 1024;
+
+// Why and by whom a poll was voided
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cancellation {
+    pub reason: String,
+    // Address that cancelled the poll
+    pub cancelled_by: String,
+    // Unix timestamp of the cancellation
+    pub cancelled_at: u64,
+}
+
+// Fields to change in `update_poll`; fields left as None are kept
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct PollUpdate {
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub options: Option<Vec<PollOption>>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+}
+
+// One entry in a poll's edit history
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PollEdit {
+    // Address that made the edit
+    pub editor: String,
+    // Unix timestamp of the edit
+    pub edited_at: u64,
+    // Values the edit replaced; only changed fields are set
+    pub previous: PollUpdate,
+}
+
+// How a tie for first place is resolved
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TieBreakPolicy {
+    // The tied option with the lowest index wins
+    #[default]
+    LowestIndex,
+    // The tied option that reached the winning count first wins
+    EarliestVote,
+    // Finalization spawns a runoff poll between the tied options, open for
+    // `duration` seconds
+    Runoff { duration: u64 },
+    // Pseudo-random draw made at finalization, seeded from a value committed
+    // at creation (such as a block hash) together with the final vote root,
+    // so nobody can predict or steer it while votes are still open
+    SeededDraw { seed: Hash },
+}
+
+// Follow-up poll opened at finalization when no option wins a majority
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RunoffConfig {
+    // Share of all votes the leader needs to avoid a runoff, in basis points
+    pub majority_bps: u32,
+    // Number of leading options carried into the runoff (options tied at the
+    // cut-off are all carried over)
+    pub top_n: u32,
+    // Seconds the runoff stays open
+    pub duration: u64,
+}
+
+// How votes in a poll are cast and counted
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum PollMethod {
+    // Each voter picks a single option
+    #[default]
+    Plurality,
+    // Each voter may score every option from `min` to `max`; the option
+    // with the highest score sum wins
+    Score { min: u32, max: u32 },
+    // Each voter ranks some or all options; `rule` picks the Condorcet
+    // method that decides the winner
+    Ranked { rule: CondorcetRule },
+    // Each voter ranks some or all options; options earn points for each
+    // position they are ranked in and the most points wins
+    Positional { rule: PositionalRule },
+    // Each voter ranks some or all options; `seats` options are elected by
+    // Single Transferable Vote
+    Stv { seats: u32 },
+    // Each voter spreads a budget of points across the options however they
+    // like; the option with the most points wins
+    Cumulative(CumulativeConfig),
+    // Each voter grades every option on the `grades` scale, listed from worst
+    // to best; options are ranked by median grade (majority judgment)
+    MajorityJudgment { grades: Vec<String> },
+    // Each voter scores every option from 0 to 5; the two highest-scoring
+    // options go to an automatic runoff decided by the ballots' preferences
+    Star,
+    // Each voter approves between one and `max_choices` options; the option
+    // with the most approvals wins
+    Approval { max_choices: u32 },
+}
+
+// Point budget for cumulative voting
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CumulativeConfig {
+    // Points per voter, or per unit of weight when `weights` is set
+    pub points: u64,
+    // Whether a ballot must spend the whole budget rather than stay within it
+    pub exact: bool,
+    // Wallet weights, such as share holdings; when set, only listed wallets
+    // may vote and each gets `points * weight`
+    pub weights: Option<BTreeMap<String, u64>>,
+}
+
+impl CumulativeConfig {
+    // Check the budget is positive and can never overflow
+    pub fn is_valid(&self) -> bool {
+        if self.points == 0 {
+            return false;
+        }
+
+        match &self.weights {
+            Some(weights) => weights
+                .values()
+                .all(|weight| *weight > 0 && weight.checked_mul(self.points).is_some()),
+            None => true,
+        }
+    }
+
+    // Points available to a wallet, or None if it has no weight
+    pub fn budget(&self, wallet_address: &str) -> Option<u64> {
+        match &self.weights {
+            Some(weights) => weights.get(wallet_address).map(|weight| weight * self.points),
+            None => Some(self.points),
+        }
+    }
+}
+
+impl PollMethod {
+    // Whether ballots are rankings of the options
+    pub fn uses_rankings(&self) -> bool {
+        matches!(
+            self,
+            PollMethod::Ranked { .. } | PollMethod::Positional { .. } | PollMethod::Stv { .. }
+        )
+    }
+
+    // Lowest and highest score for methods whose ballots are scores
+    pub fn score_range(&self) -> Option<(u32, u32)> {
+        match self {
+            PollMethod::Score { min, max } => Some((*min, *max)),
+            PollMethod::Star => Some((0, star::STAR_MAX_SCORE)),
+            _ => None,
+        }
+    }
+}
+
+// A single voter's ballot in a poll
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VoteChoice {
+    // Option index for a plurality poll
+    Single(u32),
+    // One entry per option for a score or STAR poll; None leaves the option
+    // unscored
+    Scores(Vec<Option<u32>>),
+    // Option indices from most to least preferred for a ranked poll;
+    // unranked options count as below all ranked ones
+    Ranking(Vec<u32>),
+    // Points given to each option for a cumulative poll
+    Points(Vec<u64>),
+    // One grade index per option for a majority judgment poll, where 0 is
+    // the worst grade on the scale
+    Grades(Vec<u32>),
+    // Distinct option indices approved in an approval poll
+    Approvals(Vec<u32>),
+}
+
+// How much a single wallet's vote counts for
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum VotingPower {
+    // Every wallet counts once
+    #[default]
+    OnePerWallet,
+    // Each vote counts for the wallet's vote-escrow power at the poll's
+    // snapshot, taken at `start_time`
+    Escrow,
+}
+
+// Per-poll options chosen at creation
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct PollSettings {
+    pub method: PollMethod,
+    pub voting_power: VotingPower,
+    pub tie_break: TieBreakPolicy,
+    pub runoff: Option<RunoffConfig>,
+    // Wallets allowed to vote; anyone may vote when unset
+    pub eligible_voters: Option<BTreeSet<String>>,
+}
+
+// Size limits for option metadata, in bytes
+pub const MAX_LABEL_LEN: usize = 128;
+pub const MAX_OPTION_DESCRIPTION_LEN: usize = 2048;
+pub const MAX_URI_LEN: usize = 512;
+pub const MAX_PROPOSER_LEN: usize = 128;
+
+// A single choice in a poll, with optional metadata for grant rounds and
+// similar proposals
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct PollOption {
+    pub label: String,
+    pub description: String,
+    // Link to the full proposal
+    pub uri: Option<String>,
+    // Address of whoever put the option forward
+    pub proposer: Option<String>,
+    // Hash of an image stored off-chain
+    pub image_hash: Option<Hash>,
+}
+
+impl PollOption {
+    // Create an option with a label and no metadata
+    pub fn new(label: impl Into<String>) -> Self {
+        PollOption {
+            label: label.into(),
+            ..PollOption::default()
+        }
+    }
+
+    // Check the label is present and all metadata is within size limits
+    pub fn validate(&self) -> bool {
+        !self.label.trim().is_empty()
+            && self.label.len() <= MAX_LABEL_LEN
+            && self.description.len() <= MAX_OPTION_DESCRIPTION_LEN
+            && self.uri.as_ref().is_none_or(|uri| uri.len() <= MAX_URI_LEN)
+            && self.proposer.as_ref().is_none_or(|p| p.len() <= MAX_PROPOSER_LEN)
+    }
+}
+
+impl From<String> for PollOption {
+    fn from(label: String) -> Self {
+        PollOption::new(label)
+    }
+}
+
+impl From<&str> for PollOption {
+    fn from(label: &str) -> Self {
+        PollOption::new(label)
+    }
+}
+
+// Lifecycle stage of a poll at a given time
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PollStatus {
+    Upcoming,  // Active, but voting has not started
+    Open,      // Active and within the voting window
+    Ended,     // Active, but the voting window has passed
+    Closed,    // Closed manually or by process_expired_polls
+    Finalized, // Results frozen by finalize_poll
+    Cancelled, // Voided by cancel_poll; results are not valid
+}
+
+impl Poll {
+    // Labels of all options in index order
+    pub fn option_labels(&self) -> Vec<&str> {
+        self.options.iter().map(|option| option.label.as_str()).collect()
+    }
+
+    // Check that a wallet's ballot matches the poll's method and options
+    pub fn validate_choice(&self, wallet_address: &str, choice: &VoteChoice) -> Result<(), ContractError> {
+        let option_count = self.options.len();
+
+        match (&self.settings.method, choice) {
+            (PollMethod::Plurality, VoteChoice::Single(index)) => {
+                if *index as usize >= option_count {
+                    return Err(ContractError::InvalidOption);
+                }
+            }
+            (PollMethod::Score { .. } | PollMethod::Star, VoteChoice::Scores(scores)) => {
+                let (min, max) = self.settings.method.score_range().unwrap();
+                if scores.len() != option_count || scores.iter().all(Option::is_none) {
+                    return Err(ContractError::InvalidOption);
+                }
+                if scores.iter().flatten().any(|score| *score < min || *score > max) {
+                    return Err(ContractError::ScoreOutOfRange);
+                }
+            }
+            (method, VoteChoice::Ranking(ranking)) if method.uses_rankings() => {
+                let unique: BTreeSet<&u32> = ranking.iter().collect();
+                if ranking.is_empty()
+                    || unique.len() != ranking.len()
+                    || ranking.iter().any(|index| *index as usize >= option_count)
+                {
+                    return Err(ContractError::InvalidOption);
+                }
+            }
+            (PollMethod::Cumulative(config), VoteChoice::Points(points)) => {
+                let budget = config.budget(wallet_address).ok_or(ContractError::NotEligible)?;
+                if points.len() != option_count {
+                    return Err(ContractError::InvalidOption);
+                }
+
+                let spent = points
+                    .iter()
+                    .try_fold(0u64, |total, points| total.checked_add(*points))
+                    .ok_or(ContractError::OverBudget)?;
+                if spent == 0 {
+                    return Err(ContractError::InvalidOption);
+                }
+                if spent > budget {
+                    return Err(ContractError::OverBudget);
+                }
+                if config.exact && spent != budget {
+                    return Err(ContractError::UnderBudget);
+                }
+            }
+            (PollMethod::MajorityJudgment { grades: scale }, VoteChoice::Grades(grades)) => {
+                if grades.len() != option_count {
+                    return Err(ContractError::InvalidOption);
+                }
+                if grades.iter().any(|grade| *grade as usize >= scale.len()) {
+                    return Err(ContractError::ScoreOutOfRange);
+                }
+            }
+            (PollMethod::Approval { max_choices }, VoteChoice::Approvals(indices)) => {
+                let unique: BTreeSet<&u32> = indices.iter().collect();
+                if indices.is_empty()
+                    || unique.len() != indices.len()
+                    || indices.len() > *max_choices as usize
+                    || indices.iter().any(|index| *index as usize >= option_count)
+                {
+                    return Err(ContractError::InvalidOption);
+                }
+            }
+            _ => return Err(ContractError::MethodMismatch),
+        }
+
+        Ok(())
+    }
+
+    // Whether the poll was voided by `cancel_poll`
+    pub fn is_cancelled(&self) -> bool {
+        self.cancellation.is_some()
+    }
+
+    // Derive the poll's status at the given Unix timestamp
+    pub fn status(&self, now: u64) -> PollStatus {
+        if self.is_cancelled() {
+            PollStatus::Cancelled
+        } else if self.finalized {
+            PollStatus::Finalized
+        } else if !self.active {
+            PollStatus::Closed
+        } else if now < self.start_time {
+            PollStatus::Upcoming
+        } else if now > self.end_time {
+            PollStatus::Ended
+        } else {
+            PollStatus::Open
+        }
+    }
 }
 
 // Results of a poll
 pub struct VoteResults {
-    // Mapping of option_index to vote count
+    // Mapping of option_index to vote count (summed voting power in escrow
+    // polls)
     pub counts: HashMap<u32, u64>,
     // Total number of votes cast
     pub total_votes: u64,
+    // Mapping of option_index to the ordinal (1-based) of the vote that
+    // brought it to its current count
+    pub reached_at: HashMap<u32, u64>,
+    // Mapping of option_index to score statistics; empty unless the poll
+    // uses score or STAR voting
+    pub scores: HashMap<u32, ScoreTally>,
+    // pairwise[i][j]: voters who ranked (or in STAR polls, scored) option i
+    // above option j; empty unless the poll uses ranked or STAR voting
+    pub pairwise: Vec<Vec<u64>>,
+    // positions[i][p]: voters who ranked option i in 0-based position p;
+    // empty unless the poll uses ranked voting
+    pub positions: Vec<Vec<u64>>,
+    // Every ranking in the order cast, for tallies such as STV that need
+    // whole ballots; empty unless the poll uses ranked voting
+    pub rankings: Vec<Vec<u32>>,
+    // grades[i][g]: voters who gave option i grade g; empty unless the poll
+    // uses majority judgment
+    pub grades: Vec<Vec<u64>>,
+}
+
+// Score statistics for one option of a score poll
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ScoreTally {
+    // Sum of all scores given to the option
+    pub sum: u64,
+    // Voters who scored the option
+    pub scored: u64,
+    // Voters who left the option unscored
+    pub unscored: u64,
+    // Mean score among voters who scored the option, in thousandths of a
+    // point, rounded down
+    pub average_milli: u64,
 }
 
 impl VoteResults {
@@ -39,6 +455,138 @@ impl VoteResults {
         VoteResults {
             counts,
             total_votes: 0,
+            reached_at: HashMap::new(),
+            scores: HashMap::new(),
+            pairwise: Vec::new(),
+            positions: Vec::new(),
+            rankings: Vec::new(),
+            grades: Vec::new(),
+        }
+    }
+
+    // Create empty results shaped for the poll's voting method
+    pub fn for_poll(poll: &Poll) -> Self {
+        let mut results = VoteResults::new(poll.options.len());
+
+        let option_count = poll.options.len();
+        if poll.settings.method.score_range().is_some() {
+            results.scores = (0..option_count as u32)
+                .map(|index| (index, ScoreTally::default()))
+                .collect();
         }
+        // Every ranked poll keeps enough to run any ranked tally afterwards
+        if poll.settings.method.uses_rankings() {
+            results.pairwise = vec![vec![0; option_count]; option_count];
+            results.positions = vec![vec![0; option_count]; option_count];
+        }
+        if poll.settings.method == PollMethod::Star {
+            results.pairwise = vec![vec![0; option_count]; option_count];
+        }
+        if let PollMethod::MajorityJudgment { grades } = &poll.settings.method {
+            results.grades = vec![vec![0; grades.len()]; option_count];
+        }
+
+        results
+    }
+
+    // Count a ballot that has already passed `Poll::validate_choice`
+    pub fn record(&mut self, choice: &VoteChoice) -> Result<(), ContractError> {
+        self.record_weighted(choice, 1)
     }
-}
\ No newline at end of file
+
+    // Count a ballot with a voting weight, which applies to plurality
+    // ballots; other methods are always unweighted. In
+    // score and STAR polls `counts` holds each option's score sum, in ranked polls its
+    // first preferences, in cumulative polls its points, in majority
+    // judgment polls the sum of its grade indices, and in approval polls its
+    // approvals.
+    pub fn record_weighted(&mut self, choice: &VoteChoice, weight: u64) -> Result<(), ContractError> {
+        // Check the additions first, so a ballot that would overflow leaves
+        // the tally untouched
+        let fits = match choice {
+            VoteChoice::Single(index) => self.counts[index].checked_add(weight).is_some(),
+            VoteChoice::Points(points) => points
+                .iter()
+                .enumerate()
+                .all(|(index, points)| self.counts[&(index as u32)].checked_add(*points).is_some()),
+            _ => true,
+        };
+        if !fits {
+            return Err(ContractError::TallyOverflow);
+        }
+
+        self.total_votes += 1;
+
+        match choice {
+            VoteChoice::Single(index) => {
+                *self.counts.get_mut(index).unwrap() += weight;
+                self.reached_at.insert(*index, self.total_votes);
+            }
+            VoteChoice::Scores(scores) => {
+                for (index, score) in scores.iter().enumerate() {
+                    let index = index as u32;
+                    let tally = self.scores.get_mut(&index).unwrap();
+
+                    match score {
+                        Some(score) => {
+                            tally.sum += *score as u64;
+                            tally.scored += 1;
+                            tally.average_milli = tally.sum * 1000 / tally.scored;
+                            self.counts.insert(index, tally.sum);
+                            if *score > 0 {
+                                self.reached_at.insert(index, self.total_votes);
+                            }
+                        }
+                        None => tally.unscored += 1,
+                    }
+                }
+                // Only STAR polls keep score preferences
+                if !self.pairwise.is_empty() {
+                    star::record_scores(&mut self.pairwise, scores);
+                }
+            }
+            VoteChoice::Ranking(ranking) => {
+                *self.counts.get_mut(&ranking[0]).unwrap() += 1;
+                self.reached_at.insert(ranking[0], self.total_votes);
+                condorcet::record_ranking(&mut self.pairwise, ranking);
+                for (position, index) in ranking.iter().enumerate() {
+                    self.positions[*index as usize][position] += 1;
+                }
+                self.rankings.push(ranking.clone());
+            }
+            VoteChoice::Points(points) => {
+                for (index, points) in points.iter().enumerate() {
+                    if *points > 0 {
+                        let index = index as u32;
+                        *self.counts.get_mut(&index).unwrap() += points;
+                        self.reached_at.insert(index, self.total_votes);
+                    }
+                }
+            }
+            VoteChoice::Grades(grades) => {
+                for (index, grade) in grades.iter().enumerate() {
+                    self.grades[index][*grade as usize] += 1;
+                    if *grade > 0 {
+                        let index = index as u32;
+                        *self.counts.get_mut(&index).unwrap() += *grade as u64;
+                        self.reached_at.insert(index, self.total_votes);
+                    }
+                }
+            }
+            VoteChoice::Approvals(indices) => {
+                for index in indices {
+                    *self.counts.get_mut(index).unwrap() += 1;
+                    self.reached_at.insert(*index, self.total_votes);
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// Canonical form of an option label used to detect duplicates: NFKC
+// normalized, trimmed and lowercased, so "Yes", " yes" and "Ｙｅｓ" collide
+pub fn normalize_label(label: &str) -> String {
+    label.nfkc().collect::<String>().trim().to_lowercase()
+}
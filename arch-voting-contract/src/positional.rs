@@ -0,0 +1,92 @@
+// Positional scoring rules (Borda, Dowdall, custom weights) over ranked ballots
+use crate::models::{Poll, VoteResults};
+
+// Fixed-point scale for points, so fractional weights stay exact integers:
+// one whole point is POINT_SCALE units
+pub const POINT_SCALE: u128 = 1_000_000;
+
+// Points awarded for each ranking position
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PositionalRule {
+    // n - 1 points for first place down to 0 for last, with n options
+    Borda,
+    // 1 / position points: 1, 1/2, 1/3, ...
+    Dowdall,
+    // Whole points for first place, second place, and so on; positions past
+    // the end of the list earn nothing
+    Custom(Vec<u64>),
+}
+
+impl PositionalRule {
+    // Whether the rule can award any points at all
+    pub fn is_valid(&self) -> bool {
+        match self {
+            PositionalRule::Borda | PositionalRule::Dowdall => true,
+            PositionalRule::Custom(weights) => weights.iter().any(|weight| *weight > 0),
+        }
+    }
+
+    // Points for a 0-based position, in POINT_SCALE units. Dowdall weights
+    // are rounded down.
+    pub fn weight(&self, position: usize, option_count: usize) -> u128 {
+        match self {
+            PositionalRule::Borda => (option_count - 1 - position) as u128 * POINT_SCALE,
+            PositionalRule::Dowdall => POINT_SCALE / (position as u128 + 1),
+            PositionalRule::Custom(weights) => match weights.get(position) {
+                Some(weight) => *weight as u128 * POINT_SCALE,
+                None => 0,
+            },
+        }
+    }
+}
+
+// Points tally of a ranked poll under one positional rule
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PositionalResults {
+    pub poll_id: u64,
+    pub rule: PositionalRule,
+    // First preferences per option, as in VoteResults.counts
+    pub counts: Vec<u64>,
+    // Points per option in POINT_SCALE units; unranked options earn nothing
+    pub points: Vec<u128>,
+    // Option indices from most to fewest points, ties in index order
+    pub ranking: Vec<u32>,
+}
+
+impl PositionalResults {
+    // Apply a rule to the poll's position histogram
+    pub fn new(poll: &Poll, results: &VoteResults, rule: PositionalRule) -> Self {
+        let option_count = poll.options.len();
+
+        let points: Vec<u128> = results
+            .positions
+            .iter()
+            .map(|histogram| {
+                histogram
+                    .iter()
+                    .enumerate()
+                    .map(|(position, voters)| *voters as u128 * rule.weight(position, option_count))
+                    .sum()
+            })
+            .collect();
+
+        let mut ranking: Vec<u32> = (0..option_count as u32).collect();
+        ranking.sort_by_key(|index| std::cmp::Reverse(points[*index as usize]));
+
+        PositionalResults {
+            poll_id: poll.id,
+            rule,
+            counts: (0..option_count as u32).map(|index| results.counts[&index]).collect(),
+            points,
+            ranking,
+        }
+    }
+
+    // Options sharing the highest points total
+    pub fn leaders(&self) -> Vec<u32> {
+        let top = self.points.iter().max().copied().unwrap_or(0);
+        (0..self.points.len() as u32)
+            .filter(|index| self.points[*index as usize] == top)
+            .collect()
+    }
+}
@@ -0,0 +1,125 @@
+// Paginated and filtered poll queries
+use crate::models::{PollStatus, VoteChoice};
+
+// Page size used when a query does not set one
+pub const DEFAULT_PAGE_SIZE: usize = 20;
+
+// Largest page a single query may return
+pub const MAX_PAGE_SIZE: usize = 100;
+
+// Field that query results are ordered by (ties are broken by poll ID)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PollSortKey {
+    #[default]
+    Id,
+    StartTime,
+    EndTime,
+    VoteCount,
+}
+
+// Position of the last poll returned, used to request the next page
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct PollCursor {
+    pub sort_value: u64,
+    pub poll_id: u64,
+}
+
+// Filters, ordering and page position for `query_polls`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PollQuery {
+    pub creator: Option<String>,
+    pub status: Option<PollStatus>,
+    // Only polls whose voting window overlaps [from, to]
+    pub window: Option<(u64, u64)>,
+    pub tag: Option<String>,
+    // Case-insensitive substring of the title
+    pub title_contains: Option<String>,
+    pub sort_by: PollSortKey,
+    pub descending: bool,
+    pub limit: usize,
+    // Cursor returned with the previous page
+    pub after: Option<PollCursor>,
+}
+
+impl Default for PollQuery {
+    fn default() -> Self {
+        PollQuery {
+            creator: None,
+            status: None,
+            window: None,
+            tag: None,
+            title_contains: None,
+            sort_by: PollSortKey::Id,
+            descending: false,
+            limit: DEFAULT_PAGE_SIZE,
+            after: None,
+        }
+    }
+}
+
+// One page of query results
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PollPage {
+    pub poll_ids: Vec<u64>,
+    // Present when more results follow this page
+    pub next_cursor: Option<PollCursor>,
+}
+
+// Sort cursors, skip past the requested position and cut one page. Only the
+// sort value is reversed for descending order; ties stay in poll ID order.
+pub fn paginate(mut entries: Vec<PollCursor>, query: &PollQuery) -> PollPage {
+    let key = |entry: &PollCursor| {
+        let value = if query.descending { u64::MAX - entry.sort_value } else { entry.sort_value };
+        (value, entry.poll_id)
+    };
+    entries.sort_by_key(key);
+
+    let limit = query.limit.clamp(1, MAX_PAGE_SIZE);
+    let mut remaining = entries.into_iter().filter(|entry| match query.after {
+        Some(after) => key(entry) > key(&after),
+        None => true,
+    });
+
+    let page: Vec<PollCursor> = remaining.by_ref().take(limit).collect();
+    let next_cursor = match remaining.next() {
+        Some(_) => page.last().copied(),
+        None => None,
+    };
+
+    PollPage {
+        poll_ids: page.iter().map(|entry| entry.poll_id).collect(),
+        next_cursor,
+    }
+}
+
+// Page position for listings keyed by poll ID
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PageRequest {
+    // Poll ID returned as `next_cursor` with the previous page
+    pub after: Option<u64>,
+    pub limit: usize,
+}
+
+impl Default for PageRequest {
+    fn default() -> Self {
+        PageRequest {
+            after: None,
+            limit: DEFAULT_PAGE_SIZE,
+        }
+    }
+}
+
+// A vote cast by a wallet
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WalletVote {
+    pub poll_id: u64,
+    pub choice: VoteChoice,
+}
+
+// One page of a wallet's voting history, ordered by poll ID
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WalletVotesPage {
+    pub votes: Vec<WalletVote>,
+    // Present when more results follow this page
+    pub next_cursor: Option<u64>,
+}
@@ -0,0 +1,258 @@
+// Ordered, deterministic views over poll results
+use sha2::{Digest, Sha256};
+
+use crate::condorcet::CondorcetResults;
+use crate::judgment::MajorityJudgmentResults;
+use crate::positional::PositionalResults;
+use crate::star::StarResults;
+use crate::stv::StvResults;
+use crate::merkle::Hash;
+use crate::models::{Poll, PollMethod, PollOption, TieBreakPolicy, VoteResults};
+
+// Basis points in a whole (100.00%)
+pub const BASIS_POINTS: u32 = 10_000;
+
+// How exact shares are rounded to whole basis points
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RoundingMode {
+    // Round every share down; the total may fall short of 100.00%
+    Floor,
+    // Round down, then hand the missing basis points to the largest
+    // remainders (ties to the lower index) so the total is exactly 100.00%
+    #[default]
+    LargestRemainder,
+}
+
+// Shares of `total` in basis points, computed with integer arithmetic only.
+// `total` is wide enough for the sum of any number of counts. Returns all
+// zeros when there are no votes.
+pub fn shares_bps(counts: &[u64], total: u128, mode: RoundingMode) -> Vec<u32> {
+    if total == 0 {
+        return vec![0; counts.len()];
+    }
+
+    let scaled: Vec<u128> = counts
+        .iter()
+        .map(|count| *count as u128 * BASIS_POINTS as u128)
+        .collect();
+    let mut shares: Vec<u32> = scaled.iter().map(|s| (s / total) as u32).collect();
+
+    if mode == RoundingMode::LargestRemainder {
+        let assigned: u32 = shares.iter().sum();
+        let missing = BASIS_POINTS.saturating_sub(assigned) as usize;
+
+        let mut by_remainder: Vec<usize> = (0..counts.len()).collect();
+        by_remainder.sort_by_key(|i| std::cmp::Reverse(scaled[*i] % total));
+        for i in by_remainder.into_iter().take(missing) {
+            shares[i] += 1;
+        }
+    }
+
+    shares
+}
+
+// Tally for a single option
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OptionResult {
+    pub index: u32,
+    // The option with its label and metadata
+    pub option: PollOption,
+    pub count: u64,
+    // Share of all votes (all points in score polls) in basis points
+    // (10000 = 100.00%)
+    pub share_bps: u32,
+    // Competition rank: 1 for the most votes, tied options share a rank
+    pub rank: u32,
+}
+
+// All options of a poll in index order, plus their ranking
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OrderedResults {
+    pub poll_id: u64,
+    pub total_votes: u64,
+    pub options: Vec<OptionResult>,
+    // Option indices from most to fewest votes, ties in index order
+    pub ranking: Vec<u32>,
+}
+
+impl OrderedResults {
+    // Build the ordered view from a poll and its tally
+    pub fn new(poll: &Poll, results: &VoteResults, rounding: RoundingMode) -> Self {
+        let counts: Vec<u64> = (0..poll.options.len() as u32)
+            .map(|index| results.counts[&index])
+            .collect();
+
+        // Equal to total_votes in plurality polls; the total score in score
+        // polls. Summed in u128, since weighted counts can each be near u64::MAX.
+        let total: u128 = counts.iter().map(|count| *count as u128).sum();
+        let shares = shares_bps(&counts, total, rounding);
+
+        let mut ranking: Vec<u32> = (0..counts.len() as u32).collect();
+        ranking.sort_by_key(|index| std::cmp::Reverse(counts[*index as usize]));
+
+        let options = poll
+            .options
+            .iter()
+            .enumerate()
+            .map(|(index, option)| {
+                let count = counts[index];
+                // One more than the number of options with strictly more votes
+                let rank = 1 + counts.iter().filter(|other| **other > count).count() as u32;
+
+                OptionResult {
+                    index: index as u32,
+                    option: option.clone(),
+                    count,
+                    share_bps: shares[index],
+                    rank,
+                }
+            })
+            .collect();
+
+        OrderedResults {
+            poll_id: poll.id,
+            total_votes: results.total_votes,
+            options,
+            ranking,
+        }
+    }
+}
+
+// Who won a poll once voting is over
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PollOutcome {
+    // Nobody voted
+    NoVotes,
+    // A single winner; `tie_broken` is set when the tie-break policy decided it
+    Winner { option_index: u32, tie_broken: bool },
+    // Options tied for first place and the policy defers to a runoff
+    Tie { options: Vec<u32> },
+    // Options elected to the seats of a multi-winner poll, in election order
+    Elected { options: Vec<u32> },
+}
+
+// Apply the poll's tie-break policy to its results
+pub fn decide_winner(poll: &Poll, results: &VoteResults) -> PollOutcome {
+    if results.total_votes == 0 {
+        return PollOutcome::NoVotes;
+    }
+
+    if let PollMethod::Stv { seats } = poll.settings.method {
+        let stv = StvResults::new(poll.id, poll.options.len(), seats, &results.rankings);
+        return PollOutcome::Elected { options: stv.elected };
+    }
+
+    let tied = leaders(poll, results);
+
+    if tied.len() == 1 {
+        return PollOutcome::Winner {
+            option_index: tied[0],
+            tie_broken: false,
+        };
+    }
+
+    let option_index = match poll.settings.tie_break {
+        TieBreakPolicy::LowestIndex => tied[0],
+        // Options that never gained a vote sort last
+        TieBreakPolicy::EarliestVote => *tied
+            .iter()
+            .min_by_key(|index| results.reached_at.get(index).copied().unwrap_or(u64::MAX))
+            .unwrap(),
+        // The draw is only made once finalization fixes its seed
+        TieBreakPolicy::SeededDraw { .. } => match &poll.draw_seed {
+            Some(seed) => tied[seeded_draw(seed, poll.id, &tied)],
+            None => return PollOutcome::Tie { options: tied },
+        },
+        TieBreakPolicy::Runoff { .. } => return PollOutcome::Tie { options: tied },
+    };
+
+    PollOutcome::Winner {
+        option_index,
+        tie_broken: true,
+    }
+}
+
+// Options sharing first place under the poll's method, before tie-breaking
+fn leaders(poll: &Poll, results: &VoteResults) -> Vec<u32> {
+    match &poll.settings.method {
+        PollMethod::Ranked { rule } => {
+            return CondorcetResults::new(poll.id, &results.pairwise).winners(*rule).to_vec();
+        }
+        PollMethod::Positional { rule } => {
+            return PositionalResults::new(poll, results, rule.clone()).leaders();
+        }
+        PollMethod::MajorityJudgment { grades } => {
+            return MajorityJudgmentResults::new(poll, results, grades).winners;
+        }
+        PollMethod::Star => {
+            return StarResults::new(poll, results).winners;
+        }
+        _ => {}
+    }
+
+    let top = (0..poll.options.len() as u32)
+        .map(|index| results.counts[&index])
+        .max()
+        .unwrap_or(0);
+    (0..poll.options.len() as u32)
+        .filter(|index| results.counts[index] == top)
+        .collect()
+}
+
+// Options to carry into a majority runoff, or None when the leader already
+// has the configured majority (or there is nothing to narrow down)
+pub fn majority_runoff(poll: &Poll, results: &VoteResults) -> Option<Vec<u32>> {
+    let config = poll.settings.runoff?;
+    if results.total_votes == 0 {
+        return None;
+    }
+
+    let mut ranking: Vec<u32> = (0..poll.options.len() as u32).collect();
+    ranking.sort_by_key(|index| std::cmp::Reverse(results.counts[index]));
+
+    let total: u128 = results.counts.values().map(|count| *count as u128).sum();
+    let leader = results.counts[&ranking[0]] as u128;
+    if leader * BASIS_POINTS as u128 >= config.majority_bps as u128 * total {
+        return None;
+    }
+
+    // Keep the top N, plus anything tied with the last of them
+    let last = (config.top_n as usize).min(ranking.len()) - 1;
+    let cutoff = results.counts[&ranking[last]];
+    let mut finalists: Vec<u32> = ranking
+        .into_iter()
+        .filter(|index| results.counts[index] >= cutoff)
+        .collect();
+
+    if finalists.len() >= poll.options.len() {
+        return None;
+    }
+
+    finalists.sort();
+    Some(finalists)
+}
+
+// Seed for a poll's tie-break draw: the seed committed at creation mixed
+// with the final vote root
+pub fn draw_seed(committed: &Hash, vote_root: &Hash) -> Hash {
+    let mut hasher = Sha256::new();
+    hasher.update(b"draw");
+    hasher.update(committed);
+    hasher.update(vote_root);
+    hasher.finalize().into()
+}
+
+// Deterministically pick a position in `tied` from the finalized seed
+fn seeded_draw(seed: &[u8; 32], poll_id: u64, tied: &[u32]) -> usize {
+    let mut hasher = Sha256::new();
+    hasher.update(seed);
+    hasher.update(poll_id.to_be_bytes());
+    for index in tied {
+        hasher.update(index.to_be_bytes());
+    }
+    let digest = hasher.finalize();
+
+    let mut head = [0u8; 8];
+    head.copy_from_slice(&digest[..8]);
+    (u64::from_be_bytes(head) % tied.len() as u64) as usize
+}
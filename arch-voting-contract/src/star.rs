@@ -0,0 +1,69 @@
+// STAR voting: Score Then Automatic Runoff
+use crate::models::{Poll, VoteResults};
+
+// Highest score on a STAR ballot; scores run from 0 to STAR_MAX_SCORE
+pub const STAR_MAX_SCORE: u32 = 5;
+
+// Scoring round and automatic runoff of a STAR poll
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StarResults {
+    pub poll_id: u64,
+    // Score total of each option in index order
+    pub totals: Vec<u64>,
+    // The two highest-scoring options, higher total first (ties to the lower
+    // index)
+    pub finalists: Vec<u32>,
+    // Ballots that scored each finalist above the other, aligned with
+    // `finalists`
+    pub preferred: Vec<u64>,
+    // Ballots that scored both finalists the same
+    pub no_preference: u64,
+    // Runoff winner; both finalists if they tie on preferences and totals
+    pub winners: Vec<u32>,
+}
+
+impl StarResults {
+    // Pick the two finalists by score total, then let the ballots' pairwise
+    // preferences decide between them. A runoff tie goes to the finalist with
+    // the higher total.
+    pub fn new(poll: &Poll, results: &VoteResults) -> Self {
+        let totals: Vec<u64> = (0..poll.options.len() as u32).map(|index| results.counts[&index]).collect();
+
+        let mut by_total: Vec<u32> = (0..totals.len() as u32).collect();
+        by_total.sort_by_key(|index| std::cmp::Reverse(totals[*index as usize]));
+        let finalists: Vec<u32> = by_total.into_iter().take(2).collect();
+
+        let (first, second) = (finalists[0] as usize, finalists[1] as usize);
+        let preferred = vec![results.pairwise[first][second], results.pairwise[second][first]];
+        let no_preference = results.total_votes - preferred[0] - preferred[1];
+
+        let winners = if preferred[0] != preferred[1] {
+            vec![if preferred[0] > preferred[1] { finalists[0] } else { finalists[1] }]
+        } else if totals[first] != totals[second] {
+            vec![finalists[0]]
+        } else {
+            finalists.clone()
+        };
+
+        StarResults {
+            poll_id: poll.id,
+            totals,
+            finalists,
+            preferred,
+            no_preference,
+            winners,
+        }
+    }
+}
+
+// Add one score ballot to a pairwise matrix: matrix[i][j] counts ballots
+// scoring option i above option j. Unscored options count as 0.
+pub fn record_scores(matrix: &mut [Vec<u64>], scores: &[Option<u32>]) {
+    for (i, score_i) in scores.iter().enumerate() {
+        for (j, score_j) in scores.iter().enumerate() {
+            if score_i.unwrap_or(0) > score_j.unwrap_or(0) {
+                matrix[i][j] += 1;
+            }
+        }
+    }
+}
@@ -0,0 +1,159 @@
+// Single Transferable Vote with the Droop quota and Gregory surplus transfers
+use std::collections::BTreeMap;
+
+// Fixed-point scale for ballot values, so fractional transfers stay exact
+// integers: a full ballot is worth VOTE_SCALE units
+pub const VOTE_SCALE: u128 = 1_000_000_000;
+
+// What happened in one round of the count
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StvAction {
+    // The candidate reached the quota; ballots it held move on at
+    // `transfer_value` (in VOTE_SCALE units) of their previous value
+    Elected {
+        option_index: u32,
+        surplus: u128,
+        transfer_value: u128,
+    },
+    // The candidate had the fewest votes; ballots it held move on at full value
+    Excluded { option_index: u32 },
+    // Only as many candidates remained as seats left, so all were elected
+    ElectedRemaining { options: Vec<u32> },
+}
+
+// One round of the count, for auditing
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StvRound {
+    // 1-based round number
+    pub round: u32,
+    // Votes held by each continuing candidate at the start of the round, in
+    // VOTE_SCALE units
+    pub tallies: BTreeMap<u32, u128>,
+    // Value of ballots with no continuing preference at the start of the round
+    pub exhausted: u128,
+    pub action: StvAction,
+    // Value passed on to each candidate by this round's action
+    pub transfers: BTreeMap<u32, u128>,
+    // Value of transferred ballots that had no further preference
+    pub non_transferable: u128,
+}
+
+// Outcome of an STV count
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StvResults {
+    pub poll_id: u64,
+    pub seats: u32,
+    // Droop quota in VOTE_SCALE units
+    pub quota: u128,
+    // Elected options in the order they were elected
+    pub elected: Vec<u32>,
+    pub rounds: Vec<StvRound>,
+}
+
+impl StvResults {
+    // Count the ballots. One candidate is elected or excluded per round:
+    // the candidate with the most votes is elected once it reaches the quota
+    // (ties to the lower index); otherwise the candidate with the fewest votes
+    // is excluded (ties to the higher index).
+    pub fn new(poll_id: u64, option_count: usize, seats: u32, rankings: &[Vec<u32>]) -> Self {
+        let quota = (rankings.len() as u128 / (seats as u128 + 1) + 1) * VOTE_SCALE;
+
+        let mut weights = vec![VOTE_SCALE; rankings.len()];
+        let mut continuing = vec![true; option_count];
+        let mut elected: Vec<u32> = Vec::new();
+        let mut rounds = Vec::new();
+
+        let holder = |ranking: &Vec<u32>, continuing: &[bool]| -> Option<u32> {
+            ranking.iter().copied().find(|index| continuing[*index as usize])
+        };
+
+        while elected.len() < seats as usize {
+            let mut tallies: BTreeMap<u32, u128> = (0..option_count as u32)
+                .filter(|index| continuing[*index as usize])
+                .map(|index| (index, 0))
+                .collect();
+            let mut exhausted = 0;
+            let holders: Vec<Option<u32>> = rankings.iter().map(|r| holder(r, &continuing)).collect();
+            for (held_by, weight) in holders.iter().zip(&weights) {
+                match held_by {
+                    Some(index) => *tallies.get_mut(index).unwrap() += weight,
+                    None => exhausted += weight,
+                }
+            }
+
+            let round = rounds.len() as u32 + 1;
+
+            if elected.len() + tallies.len() <= seats as usize {
+                let mut remaining: Vec<u32> = tallies.keys().copied().collect();
+                remaining.sort_by_key(|index| std::cmp::Reverse(tallies[index]));
+                elected.extend(&remaining);
+                rounds.push(StvRound {
+                    round,
+                    tallies,
+                    exhausted,
+                    action: StvAction::ElectedRemaining { options: remaining },
+                    transfers: BTreeMap::new(),
+                    non_transferable: 0,
+                });
+                break;
+            }
+
+            let (leader, leader_votes) = tallies
+                .iter()
+                .max_by_key(|(index, votes)| (**votes, std::cmp::Reverse(**index)))
+                .map(|(index, votes)| (*index, *votes))
+                .unwrap();
+
+            let (action, from, surplus) = if leader_votes >= quota {
+                let surplus = leader_votes - quota;
+                elected.push(leader);
+                let action = StvAction::Elected {
+                    option_index: leader,
+                    surplus,
+                    transfer_value: surplus * VOTE_SCALE / leader_votes,
+                };
+                (action, leader, Some((surplus, leader_votes)))
+            } else {
+                let loser = *tallies
+                    .iter()
+                    .min_by_key(|(index, votes)| (**votes, std::cmp::Reverse(**index)))
+                    .unwrap()
+                    .0;
+                (StvAction::Excluded { option_index: loser }, loser, None)
+            };
+            continuing[from as usize] = false;
+
+            let mut transfers = BTreeMap::new();
+            let mut non_transferable = 0;
+            for (ballot, held_by) in holders.iter().enumerate() {
+                if *held_by != Some(from) {
+                    continue;
+                }
+                if let Some((surplus, votes)) = surplus {
+                    weights[ballot] = weights[ballot] * surplus / votes;
+                }
+                match holder(&rankings[ballot], &continuing) {
+                    Some(next) => *transfers.entry(next).or_insert(0) += weights[ballot],
+                    None => non_transferable += weights[ballot],
+                }
+            }
+
+            rounds.push(StvRound {
+                round,
+                tallies,
+                exhausted,
+                action,
+                transfers,
+                non_transferable,
+            });
+        }
+
+        StvResults {
+            poll_id,
+            seats,
+            quota,
+            elected,
+            rounds,
+        }
+    }
+}
@@ -0,0 +1,77 @@
+// Tests for role-based access control
+use arch_voting_contract::{ContractError, Role, VoteChoice, VotingContract};
+
+fn create_poll(contract: &mut VotingContract, creator: &str) -> Result<u64, ContractError> {
+    contract.create_poll(
+        creator.to_string(),
+        "Access Poll".to_string(),
+        "Poll used for access control tests".to_string(),
+        vec!["Yes".to_string(), "No".to_string()],
+        0,
+        u64::MAX,
+    )
+}
+
+// Only poll creators and admins may create polls
+#[test]
+fn test_poll_creation_requires_role() {
+    let mut contract = VotingContract::new("owner".to_string());
+
+    let result = create_poll(&mut contract, "anyone");
+    assert!(matches!(result, Err(ContractError::Unauthorized)));
+
+    // The owner is implicitly an admin
+    assert!(contract.has_role(Role::Admin, "owner"));
+    create_poll(&mut contract, "owner").unwrap();
+
+    contract.grant_role(Role::PollCreator, "anyone".to_string(), "owner".to_string()).unwrap();
+    assert!(contract.has_role(Role::PollCreator, "anyone"));
+    create_poll(&mut contract, "anyone").unwrap();
+
+    contract.revoke_role(Role::PollCreator, "anyone".to_string(), "owner".to_string()).unwrap();
+    let result = create_poll(&mut contract, "anyone");
+    assert!(matches!(result, Err(ContractError::Unauthorized)));
+}
+
+// Only admins may grant or revoke roles
+#[test]
+fn test_role_management() {
+    let mut contract = VotingContract::new("owner".to_string());
+
+    let result = contract.grant_role(Role::Admin, "mallory".to_string(), "mallory".to_string());
+    assert!(matches!(result, Err(ContractError::Unauthorized)));
+
+    // A granted admin can manage roles too
+    contract.grant_role(Role::Admin, "admin".to_string(), "owner".to_string()).unwrap();
+    contract.grant_role(Role::Moderator, "mod".to_string(), "admin".to_string()).unwrap();
+    contract.grant_role(Role::Auditor, "auditor".to_string(), "admin".to_string()).unwrap();
+    assert_eq!(contract.get_role_members(Role::Admin), vec!["admin".to_string()]);
+
+    // Roles are distinct: a moderator cannot manage roles
+    let result = contract.revoke_role(Role::Auditor, "auditor".to_string(), "mod".to_string());
+    assert!(matches!(result, Err(ContractError::Unauthorized)));
+    assert!(contract.has_role(Role::Auditor, "auditor"));
+}
+
+// Moderators may close any poll; auditors may export votes
+#[test]
+fn test_moderator_and_auditor_permissions() {
+    let mut contract = VotingContract::new("owner".to_string());
+    contract.grant_role(Role::PollCreator, "creator".to_string(), "owner".to_string()).unwrap();
+    contract.grant_role(Role::Moderator, "mod".to_string(), "owner".to_string()).unwrap();
+    contract.grant_role(Role::Auditor, "auditor".to_string(), "owner".to_string()).unwrap();
+
+    let poll_id = create_poll(&mut contract, "creator").unwrap();
+    contract.vote(poll_id, "wallet2".to_string(), 1).unwrap();
+    contract.vote(poll_id, "wallet1".to_string(), 0).unwrap();
+
+    let result = contract.get_poll_votes(poll_id, "mod");
+    assert!(matches!(result, Err(ContractError::Unauthorized)));
+    let votes = contract.get_poll_votes(poll_id, "auditor").unwrap();
+    assert_eq!(votes, vec![("wallet1".to_string(), VoteChoice::Single(0)), ("wallet2".to_string(), VoteChoice::Single(1))]);
+
+    let result = contract.close_poll(poll_id, "auditor".to_string());
+    assert!(matches!(result, Err(ContractError::Unauthorized)));
+    contract.close_poll(poll_id, "mod".to_string()).unwrap();
+    assert!(!contract.get_poll(poll_id).unwrap().active);
+}
@@ -0,0 +1,129 @@
+// Tests for anchoring finalized tallies in OP_RETURN payloads
+use arch_voting_contract::anchor::{MAX_PAYLOAD_LEN, PAYLOAD_LEN_V1};
+use arch_voting_contract::{ContractError, TallyCommitment, VoteChoice, VotingContract};
+
+mod common;
+use common::setup_contract;
+
+fn finalized_poll() -> (VotingContract, u64) {
+    let mut contract = setup_contract();
+
+    let poll_id = contract.create_poll(
+        "creator".to_string(),
+        "Anchored Poll".to_string(),
+        "Results will be anchored on Bitcoin".to_string(),
+        vec!["Yes".to_string(), "No".to_string()],
+        0,
+        u64::MAX,
+    ).unwrap();
+
+    contract.vote(poll_id, "wallet1".to_string(), 0).unwrap();
+    contract.vote(poll_id, "wallet2".to_string(), 0).unwrap();
+    contract.vote(poll_id, "wallet3".to_string(), 1).unwrap();
+
+    // Finalizing an open poll is not allowed
+    let result = contract.finalize_poll(poll_id);
+    assert!(matches!(result, Err(ContractError::PollNotEnded)));
+
+    contract.close_poll(poll_id, "creator".to_string()).unwrap();
+    contract.finalize_poll(poll_id).unwrap();
+
+    (contract, poll_id)
+}
+
+// Encoding and decoding should round-trip within the size limit
+#[test]
+fn test_payload_round_trip() {
+    let (contract, poll_id) = finalized_poll();
+    let commitment = contract.get_tally_commitment(poll_id).unwrap();
+
+    let payload = commitment.encode();
+    assert_eq!(payload.len(), PAYLOAD_LEN_V1);
+    assert!(payload.len() <= MAX_PAYLOAD_LEN);
+    assert_eq!(&payload[..4], b"AVC\x01");
+    assert_eq!(TallyCommitment::decode(&payload).unwrap(), commitment);
+
+    let script = commitment.to_script();
+    assert_eq!(script[0], 0x6a);
+    assert_eq!(TallyCommitment::from_script(&script).unwrap(), commitment);
+}
+
+// Malformed payloads must be rejected
+#[test]
+fn test_invalid_payloads() {
+    let (contract, poll_id) = finalized_poll();
+    let payload = contract.get_tally_commitment(poll_id).unwrap().encode();
+
+    let mut bad_magic = payload.clone();
+    bad_magic[0] = b'X';
+    assert!(matches!(TallyCommitment::decode(&bad_magic), Err(ContractError::InvalidPayload)));
+
+    let mut bad_version = payload.clone();
+    bad_version[3] = 2;
+    assert!(matches!(TallyCommitment::decode(&bad_version), Err(ContractError::InvalidPayload)));
+
+    assert!(matches!(TallyCommitment::decode(&payload[..40]), Err(ContractError::InvalidPayload)));
+    assert!(matches!(TallyCommitment::decode(&[0u8; 81]), Err(ContractError::InvalidPayload)));
+    assert!(matches!(TallyCommitment::from_script(&payload), Err(ContractError::InvalidPayload)));
+}
+
+// A decoded payload verifies only against matching results
+#[test]
+fn test_verify_against_contract() {
+    let (mut contract, poll_id) = finalized_poll();
+    let commitment = contract.get_tally_commitment(poll_id).unwrap();
+
+    let decoded = TallyCommitment::decode(&commitment.encode()).unwrap();
+    assert!(decoded.verify(&contract).is_ok());
+
+    // Later activity does not affect the recorded commitment
+    contract.create_poll(
+        "creator".to_string(),
+        "Another Poll".to_string(),
+        "Created after finalization".to_string(),
+        vec!["A".to_string(), "B".to_string()],
+        0,
+        u64::MAX,
+    ).unwrap();
+    assert!(decoded.verify(&contract).is_ok());
+
+    let mut tampered = decoded.clone();
+    tampered.results_hash[0] ^= 0xff;
+    assert!(matches!(tampered.verify(&contract), Err(ContractError::CommitmentMismatch)));
+
+    // Polls that are not finalized cannot be verified
+    let mut unknown = decoded.clone();
+    unknown.poll_id = 1;
+    assert!(matches!(unknown.verify(&contract), Err(ContractError::PollNotFinalized)));
+
+    let result = contract.finalize_poll(poll_id);
+    assert!(matches!(result, Err(ContractError::PollAlreadyFinalized)));
+}
+
+// Voters can check their ballot against the anchored state root after the
+// rest of the state has moved on
+#[test]
+fn test_prove_vote_at_finalization() {
+    let (mut contract, poll_id) = finalized_poll();
+    let commitment = contract.get_tally_commitment(poll_id).unwrap();
+
+    let other = contract.create_poll(
+        "creator".to_string(),
+        "Another Poll".to_string(),
+        "Voted on after finalization".to_string(),
+        vec!["A".to_string(), "B".to_string()],
+        0,
+        u64::MAX,
+    ).unwrap();
+    contract.vote(other, "wallet1".to_string(), 1).unwrap();
+
+    assert!(!contract.prove_vote(poll_id, "wallet1").unwrap().verify(&commitment.state_root));
+    let proof = contract.prove_vote_at_finalization(poll_id, "wallet1").unwrap();
+    assert_eq!(proof.choice, VoteChoice::Single(0));
+    assert!(proof.verify(&commitment.state_root));
+
+    let result = contract.prove_vote_at_finalization(poll_id, "mallory");
+    assert!(matches!(result, Err(ContractError::VoteNotFound)));
+    let result = contract.prove_vote_at_finalization(other, "wallet1");
+    assert!(matches!(result, Err(ContractError::PollNotFinalized)));
+}
@@ -0,0 +1,231 @@
+// Tests for multi-question ballots
+use std::collections::BTreeSet;
+
+use arch_voting_contract::{
+    Answer, ContractError, PageRequest, PollMethod, PollOutcome, PollQuery, PollSettings, PollUpdate,
+    Question, Role, VoteChoice, VotingContract, VotingMethod,
+};
+
+fn question(prompt: &str, options: &[&str], method: VotingMethod) -> Question {
+    Question {
+        prompt: prompt.to_string(),
+        options: options.iter().map(|o| o.to_string()).collect(),
+        method,
+    }
+}
+
+fn governance_ballot(contract: &mut VotingContract, eligible: Option<BTreeSet<String>>) -> u64 {
+    contract.grant_role(Role::PollCreator, "dao".to_string(), "owner".to_string()).unwrap();
+
+    contract.create_ballot(
+        "dao".to_string(),
+        "Q3 Governance".to_string(),
+        "Quarterly proposals".to_string(),
+        vec![
+            question("Raise the grant budget?", &["Yes", "No"], VotingMethod::Plurality),
+            question("Which chains to support?", &["Bitcoin", "Arch", "Other"], VotingMethod::Approval { max_choices: 2 }),
+            question("Adopt the new logo?", &["Yes", "No"], VotingMethod::Plurality),
+        ],
+        0,
+        u64::MAX,
+        eligible,
+    ).unwrap()
+}
+
+// Each question is tallied separately, with its own turnout
+#[test]
+fn test_ballot_results_per_question() {
+    let mut contract = VotingContract::new("owner".to_string());
+    let ballot_id = governance_ballot(&mut contract, None);
+
+    contract.cast_ballot(ballot_id, "w1".to_string(), vec![
+        Answer::Choice(0),
+        Answer::Approve(vec![0, 1]),
+        Answer::Choice(1),
+    ]).unwrap();
+    contract.cast_ballot(ballot_id, "w2".to_string(), vec![
+        Answer::Choice(0),
+        Answer::Approve(vec![1]),
+        Answer::Abstain,
+    ]).unwrap();
+    contract.cast_ballot(ballot_id, "w3".to_string(), vec![
+        Answer::Abstain,
+        Answer::Approve(vec![2, 1]),
+        Answer::Choice(1),
+    ]).unwrap();
+
+    let results = contract.get_ballot_results(ballot_id).unwrap();
+    assert_eq!(results.turnout, 3);
+
+    assert_eq!(results.questions[0].counts[&0], 2);
+    assert_eq!(results.questions[0].turnout, 2);
+    assert_eq!(results.questions[0].abstentions, 1);
+
+    let approvals: Vec<u64> = (0..3).map(|i| results.questions[1].counts[&i]).collect();
+    assert_eq!(approvals, vec![1, 3, 1]);
+    assert_eq!(results.questions[1].turnout, 3);
+
+    assert_eq!(results.questions[2].counts[&1], 2);
+    assert_eq!(results.questions[2].abstentions, 1);
+
+    assert!(contract.has_cast_ballot(ballot_id, "w1").unwrap());
+    let result = contract.cast_ballot(ballot_id, "w1".to_string(), vec![Answer::Abstain; 3]);
+    assert!(matches!(result, Err(ContractError::AlreadyVoted)));
+}
+
+// A single invalid answer rejects the whole submission
+#[test]
+fn test_ballot_submission_is_atomic() {
+    let mut contract = VotingContract::new("owner".to_string());
+    let ballot_id = governance_ballot(&mut contract, None);
+
+    let invalid = [
+        vec![Answer::Choice(0), Answer::Approve(vec![0, 1, 2]), Answer::Choice(0)],
+        vec![Answer::Choice(0), Answer::Approve(vec![1, 1]), Answer::Choice(0)],
+        vec![Answer::Choice(0), Answer::Choice(1), Answer::Choice(0)],
+        vec![Answer::Choice(0), Answer::Approve(vec![0]), Answer::Choice(5)],
+        vec![Answer::Choice(0), Answer::Approve(vec![0])],
+    ];
+    for answers in invalid {
+        let result = contract.cast_ballot(ballot_id, "w1".to_string(), answers);
+        assert!(matches!(result, Err(ContractError::InvalidOption)));
+    }
+
+    let results = contract.get_ballot_results(ballot_id).unwrap();
+    assert_eq!(results.turnout, 0);
+    assert_eq!(results.questions[0].counts[&0], 0);
+    assert!(!contract.has_cast_ballot(ballot_id, "w1").unwrap());
+}
+
+// Eligibility, closure and validation of the questions themselves
+#[test]
+fn test_ballot_eligibility_and_validation() {
+    let mut contract = VotingContract::new("owner".to_string());
+    let eligible: BTreeSet<String> = ["member".to_string()].into_iter().collect();
+    let ballot_id = governance_ballot(&mut contract, Some(eligible));
+
+    let result = contract.cast_ballot(ballot_id, "outsider".to_string(), vec![Answer::Abstain; 3]);
+    assert!(matches!(result, Err(ContractError::NotEligible)));
+
+    contract.close_ballot(ballot_id, "dao".to_string()).unwrap();
+    let result = contract.cast_ballot(ballot_id, "member".to_string(), vec![Answer::Abstain; 3]);
+    assert!(matches!(result, Err(ContractError::PollNotActive)));
+
+    let result = contract.create_ballot(
+        "dao".to_string(),
+        "Bad".to_string(),
+        "Bad ballot".to_string(),
+        vec![question("Pick", &["A", "B"], VotingMethod::Approval { max_choices: 3 })],
+        0,
+        u64::MAX,
+        None,
+    );
+    assert!(matches!(result, Err(ContractError::InvalidSettings)));
+
+    let result = contract.create_ballot("dao".to_string(), "Empty".to_string(), "".to_string(), vec![], 0, 1, None);
+    assert!(matches!(result, Err(ContractError::InvalidOption)));
+
+    assert!(matches!(contract.get_ballot(99), Err(ContractError::BallotNotFound)));
+}
+
+// Questions are ordinary polls, so ballot votes are indexed, committed and
+// queried like any other vote, but only cast through the ballot
+#[test]
+fn test_ballot_questions_are_polls() {
+    let mut contract = VotingContract::new("owner".to_string());
+    let ballot_id = governance_ballot(&mut contract, None);
+    let polls = contract.get_ballot(ballot_id).unwrap().polls.clone();
+    assert_eq!(polls.len(), 3);
+    assert_eq!(contract.get_poll(polls[1]).unwrap().ballot, Some(ballot_id));
+
+    contract.cast_ballot(ballot_id, "w1".to_string(), vec![
+        Answer::Choice(0),
+        Answer::Approve(vec![0, 1]),
+        Answer::Abstain,
+    ]).unwrap();
+
+    let history = contract.get_votes_by_wallet("w1", &PageRequest::default()).votes;
+    let voted: Vec<(u64, VoteChoice)> = history.into_iter().map(|vote| (vote.poll_id, vote.choice)).collect();
+    assert_eq!(voted, vec![(polls[0], VoteChoice::Single(0)), (polls[1], VoteChoice::Approvals(vec![0, 1]))]);
+
+    let proof = contract.prove_vote(polls[1], "w1").unwrap();
+    assert!(proof.verify(&contract.get_state_root()));
+    assert!(matches!(contract.prove_vote(polls[2], "w1"), Err(ContractError::VoteNotFound)));
+
+    let page = contract.query_polls(&PollQuery {
+        title_contains: Some("chains".to_string()),
+        ..PollQuery::default()
+    });
+    assert_eq!(page.poll_ids, vec![polls[1]]);
+
+    let result = contract.vote(polls[0], "w2".to_string(), 0);
+    assert!(matches!(result, Err(ContractError::BallotQuestion)));
+    let result = contract.vote_approvals(polls[1], "w2".to_string(), vec![0]);
+    assert!(matches!(result, Err(ContractError::BallotQuestion)));
+    let update = PollUpdate { title: Some("Renamed".to_string()), ..PollUpdate::default() };
+    let result = contract.update_poll(polls[0], update, "dao".to_string());
+    assert!(matches!(result, Err(ContractError::BallotQuestion)));
+    let result = contract.close_poll(polls[0], "dao".to_string());
+    assert!(matches!(result, Err(ContractError::BallotQuestion)));
+    let result = contract.cancel_poll(polls[0], "dao".to_string(), "Typo".to_string());
+    assert!(matches!(result, Err(ContractError::BallotQuestion)));
+
+    contract.close_ballot(ballot_id, "dao".to_string()).unwrap();
+    assert!(polls.iter().all(|poll_id| !contract.get_poll(*poll_id).unwrap().active));
+}
+
+// A ballot is cancelled as a whole, voiding every question
+#[test]
+fn test_cancel_ballot() {
+    let mut contract = VotingContract::new("owner".to_string());
+    let ballot_id = governance_ballot(&mut contract, None);
+    let polls = contract.get_ballot(ballot_id).unwrap().polls.clone();
+
+    let result = contract.cancel_ballot(ballot_id, "mallory".to_string(), "Fraud".to_string());
+    assert!(matches!(result, Err(ContractError::Unauthorized)));
+    let result = contract.cancel_ballot(ballot_id, "dao".to_string(), " ".to_string());
+    assert!(matches!(result, Err(ContractError::InvalidReason)));
+
+    contract.cancel_ballot(ballot_id, "dao".to_string(), "Fraud".to_string()).unwrap();
+    assert!(!contract.get_ballot(ballot_id).unwrap().active);
+    for poll_id in &polls {
+        let poll = contract.get_poll(*poll_id).unwrap();
+        assert_eq!(poll.cancellation.as_ref().unwrap().cancelled_by, "dao");
+    }
+
+    let result = contract.cast_ballot(ballot_id, "w1".to_string(), vec![Answer::Abstain; 3]);
+    assert!(matches!(result, Err(ContractError::PollNotActive)));
+    let result = contract.cancel_ballot(ballot_id, "dao".to_string(), "Fraud".to_string());
+    assert!(matches!(result, Err(ContractError::PollCancelled)));
+}
+
+// Approval polls can also stand alone
+#[test]
+fn test_approval_poll() {
+    let mut contract = VotingContract::new("owner".to_string());
+    let create = |contract: &mut VotingContract, max_choices| {
+        contract.create_poll_with_settings(
+            "owner".to_string(),
+            "Board".to_string(),
+            "Approve up to two".to_string(),
+            vec!["Ann", "Ben", "Cat"],
+            0,
+            u64::MAX,
+            PollSettings { method: PollMethod::Approval { max_choices }, ..PollSettings::default() },
+        )
+    };
+    assert!(matches!(create(&mut contract, 0), Err(ContractError::InvalidSettings)));
+    assert!(matches!(create(&mut contract, 4), Err(ContractError::InvalidSettings)));
+    let poll_id = create(&mut contract, 2).unwrap();
+
+    contract.vote_approvals(poll_id, "w1".to_string(), vec![0, 1]).unwrap();
+    contract.vote_approvals(poll_id, "w2".to_string(), vec![1]).unwrap();
+    let result = contract.vote_approvals(poll_id, "w3".to_string(), vec![0, 1, 2]);
+    assert!(matches!(result, Err(ContractError::InvalidOption)));
+    assert!(matches!(contract.vote(poll_id, "w3".to_string(), 0), Err(ContractError::MethodMismatch)));
+
+    let results = contract.get_results(poll_id).unwrap();
+    assert_eq!((results.counts[&0], results.counts[&1], results.total_votes), (1, 2, 2));
+    contract.close_poll(poll_id, "owner".to_string()).unwrap();
+    assert_eq!(contract.get_winner(poll_id).unwrap(), PollOutcome::Winner { option_index: 1, tie_broken: false });
+}
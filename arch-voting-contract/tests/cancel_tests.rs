@@ -0,0 +1,91 @@
+// Tests for cancelling polls
+use arch_voting_contract::{ContractError, Event, PollQuery, PollStatus, Role, VotingContract};
+
+fn setup_poll(contract: &mut VotingContract) -> u64 {
+    contract.grant_role(Role::PollCreator, "creator".to_string(), "owner".to_string()).unwrap();
+    contract.create_poll(
+        "creator".to_string(),
+        "Cancel Poll".to_string(),
+        "Poll used for cancellation tests".to_string(),
+        vec!["Yes", "No"],
+        0,
+        u64::MAX,
+    ).unwrap()
+}
+
+// A cancelled poll is void: no votes, no winner, no finalization
+#[test]
+fn test_cancel_voids_poll() {
+    let mut contract = VotingContract::new("owner".to_string());
+    let poll_id = setup_poll(&mut contract);
+    contract.vote(poll_id, "alice".to_string(), 0).unwrap();
+
+    contract.cancel_poll(poll_id, "creator".to_string(), "Ballot stuffing detected".to_string()).unwrap();
+
+    let poll = contract.get_poll(poll_id).unwrap();
+    assert!(!poll.active);
+    assert!(poll.is_cancelled());
+    assert_eq!(poll.status(0), PollStatus::Cancelled);
+    let cancellation = poll.cancellation.as_ref().unwrap();
+    assert_eq!(cancellation.reason, "Ballot stuffing detected");
+    assert_eq!(cancellation.cancelled_by, "creator");
+
+    assert_eq!(contract.get_events().last(), Some(&Event::PollCancelled {
+        poll_id,
+        cancelled_by: "creator".to_string(),
+        reason: "Ballot stuffing detected".to_string(),
+    }));
+
+    assert!(matches!(contract.get_winner(poll_id), Err(ContractError::PollCancelled)));
+    assert!(matches!(contract.get_ordered_results(poll_id), Err(ContractError::PollCancelled)));
+    assert!(matches!(contract.get_detailed_results(poll_id), Err(ContractError::PollCancelled)));
+    assert_eq!(contract.get_results(poll_id).unwrap().total_votes, 1);
+    assert!(matches!(contract.finalize_poll(poll_id), Err(ContractError::PollCancelled)));
+    assert!(matches!(contract.vote(poll_id, "bob".to_string(), 1), Err(ContractError::PollNotActive)));
+    let result = contract.cancel_poll(poll_id, "creator".to_string(), "Again".to_string());
+    assert!(matches!(result, Err(ContractError::PollCancelled)));
+
+    // Cancelled polls can be found by status
+    let page = contract.query_polls(&PollQuery {
+        status: Some(PollStatus::Cancelled),
+        ..PollQuery::default()
+    });
+    assert_eq!(page.poll_ids, vec![poll_id]);
+}
+
+// Closing keeps the results valid, unlike cancelling
+#[test]
+fn test_close_is_not_cancel() {
+    let mut contract = VotingContract::new("owner".to_string());
+    let poll_id = setup_poll(&mut contract);
+    contract.vote(poll_id, "alice".to_string(), 0).unwrap();
+    contract.close_poll(poll_id, "creator".to_string()).unwrap();
+
+    assert_eq!(contract.get_poll(poll_id).unwrap().status(0), PollStatus::Closed);
+    assert!(contract.get_winner(poll_id).is_ok());
+
+    // Finalized results can no longer be voided
+    contract.finalize_poll(poll_id).unwrap();
+    let result = contract.cancel_poll(poll_id, "creator".to_string(), "Too late".to_string());
+    assert!(matches!(result, Err(ContractError::PollAlreadyFinalized)));
+}
+
+// Only the creator, a moderator or an admin may cancel, and a reason is required
+#[test]
+fn test_cancel_authorization() {
+    let mut contract = VotingContract::new("owner".to_string());
+    let poll_id = setup_poll(&mut contract);
+
+    let result = contract.cancel_poll(poll_id, "mallory".to_string(), "Because".to_string());
+    assert!(matches!(result, Err(ContractError::Unauthorized)));
+
+    let result = contract.cancel_poll(poll_id, "creator".to_string(), "   ".to_string());
+    assert!(matches!(result, Err(ContractError::InvalidReason)));
+
+    let result = contract.cancel_poll(99, "owner".to_string(), "Missing".to_string());
+    assert!(matches!(result, Err(ContractError::PollNotFound)));
+
+    contract.grant_role(Role::Moderator, "mod".to_string(), "owner".to_string()).unwrap();
+    contract.cancel_poll(poll_id, "mod".to_string(), "Spam".to_string()).unwrap();
+    assert!(contract.get_poll(poll_id).unwrap().is_cancelled());
+}
@@ -0,0 +1,57 @@
+// Fixtures shared by the integration test files; each file uses only some
+#![allow(dead_code)]
+use arch_voting_contract::{
+    Clock, ContractError, PollMethod, PollOption, PollSettings, Role, VotingContract,
+};
+
+// Unix timestamp that contracts on a manual clock start at
+pub const START: u64 = 1_700_000_000;
+
+// Contract owned by "owner" in which "creator" may open polls
+pub fn setup_contract() -> VotingContract {
+    with_creator(VotingContract::new("owner".to_string()))
+}
+
+// Same as setup_contract, on a manual clock set to START
+pub fn setup_manual_contract() -> VotingContract {
+    with_creator(VotingContract::with_clock("owner".to_string(), Clock::Manual(START)))
+}
+
+// Poll by "creator" over `options` that stays open until closed
+pub fn open_poll(
+    contract: &mut VotingContract,
+    options: Vec<impl Into<PollOption>>,
+    settings: PollSettings,
+) -> Result<u64, ContractError> {
+    contract.create_poll_with_settings(
+        "creator".to_string(),
+        "Test Poll".to_string(),
+        "Poll used by the tests".to_string(),
+        options,
+        0,
+        u64::MAX,
+        settings,
+    )
+}
+
+// Same as open_poll, with default settings apart from the voting method
+pub fn open_method_poll(
+    contract: &mut VotingContract,
+    options: Vec<impl Into<PollOption>>,
+    method: PollMethod,
+) -> Result<u64, ContractError> {
+    open_poll(contract, options, PollSettings { method, ..PollSettings::default() })
+}
+
+// Cast `count` identical ranked ballots from fresh voters, numbered on from `voter`
+pub fn cast(contract: &mut VotingContract, poll_id: u64, count: usize, ranking: &[u32], voter: &mut usize) {
+    for _ in 0..count {
+        contract.vote_ranked(poll_id, format!("voter{}", voter), ranking.to_vec()).unwrap();
+        *voter += 1;
+    }
+}
+
+fn with_creator(mut contract: VotingContract) -> VotingContract {
+    contract.grant_role(Role::PollCreator, "creator".to_string(), "owner".to_string()).unwrap();
+    contract
+}
@@ -0,0 +1,181 @@
+// Tests for ranked polls and the Condorcet tallies
+use arch_voting_contract::{
+    CondorcetRule, ContractError, PollMethod, PollOutcome, PollSettings, RunoffConfig, VoteChoice,
+    VotingContract,
+};
+
+mod common;
+use common::{cast, open_method_poll, open_poll, setup_contract};
+
+fn create_ranked_poll(contract: &mut VotingContract, options: &[&str], rule: CondorcetRule) -> u64 {
+    open_method_poll(contract, options.to_vec(), PollMethod::Ranked { rule }).unwrap()
+}
+
+// Ballot written as letters, e.g. "ACBED"
+fn letters(ballot: &str) -> Vec<u32> {
+    ballot.bytes().map(|b| (b - b'A') as u32).collect()
+}
+
+// The 45-voter example from Schulze's paper, where the two methods disagree
+fn schulze_example(rule: CondorcetRule) -> (VotingContract, u64) {
+    let mut contract = setup_contract();
+    let poll_id = create_ranked_poll(&mut contract, &["A", "B", "C", "D", "E"], rule);
+
+    let mut voter = 0;
+    for (count, ballot) in [
+        (5, "ACBED"),
+        (5, "ADECB"),
+        (8, "BEDAC"),
+        (3, "CABED"),
+        (7, "CAEBD"),
+        (2, "CBADE"),
+        (7, "DCEBA"),
+        (8, "EBADC"),
+    ] {
+        cast(&mut contract, poll_id, count, &letters(ballot), &mut voter);
+    }
+
+    (contract, poll_id)
+}
+
+// Matrix, strongest paths, both methods and the cycle are all reported
+#[test]
+fn test_schulze_and_ranked_pairs() {
+    let (mut contract, poll_id) = schulze_example(CondorcetRule::Schulze);
+    let results = contract.get_condorcet_results(poll_id).unwrap();
+
+    assert_eq!(results.matrix, vec![
+        vec![0, 20, 26, 30, 22],
+        vec![25, 0, 16, 33, 18],
+        vec![19, 29, 0, 17, 24],
+        vec![15, 12, 28, 0, 14],
+        vec![23, 27, 21, 31, 0],
+    ]);
+    assert_eq!(results.strongest_paths, vec![
+        vec![0, 28, 28, 30, 24],
+        vec![25, 0, 28, 33, 24],
+        vec![25, 29, 0, 29, 24],
+        vec![25, 28, 28, 0, 24],
+        vec![25, 28, 28, 31, 0],
+    ]);
+
+    assert_eq!(results.condorcet_winner, None);
+    assert_eq!(results.cycles, vec![vec![0, 1, 2, 3, 4]]);
+
+    assert_eq!(results.schulze_winners, vec![4]);
+    assert_eq!(results.schulze_ranking, vec![4, 0, 2, 1, 3]);
+
+    assert_eq!(results.ranked_pairs_winners, vec![0]);
+    assert_eq!(results.ranked_pairs_ranking, vec![0, 2, 4, 1, 3]);
+    assert_eq!(results.locked_pairs, vec![(1, 3), (4, 3), (0, 3), (2, 1), (4, 1), (0, 2), (2, 4)]);
+    assert_eq!(results.skipped_pairs, vec![(3, 2), (1, 0), (4, 0)]);
+
+    contract.close_poll(poll_id, "creator".to_string()).unwrap();
+    assert_eq!(contract.get_winner(poll_id).unwrap(), PollOutcome::Winner { option_index: 4, tie_broken: false });
+
+    // The same ballots under Ranked Pairs
+    let (mut contract, poll_id) = schulze_example(CondorcetRule::RankedPairs);
+    contract.close_poll(poll_id, "creator".to_string()).unwrap();
+    assert_eq!(contract.get_winner(poll_id).unwrap(), PollOutcome::Winner { option_index: 0, tie_broken: false });
+}
+
+// A Condorcet winner wins under both methods, even from partial rankings
+#[test]
+fn test_condorcet_winner() {
+    let mut contract = setup_contract();
+    let poll_id = create_ranked_poll(&mut contract, &["A", "B", "C"], CondorcetRule::Schulze);
+
+    let mut voter = 0;
+    cast(&mut contract, poll_id, 3, &letters("BA"), &mut voter);
+    cast(&mut contract, poll_id, 2, &letters("C"), &mut voter);
+    cast(&mut contract, poll_id, 2, &letters("AB"), &mut voter);
+
+    let results = contract.get_condorcet_results(poll_id).unwrap();
+    assert_eq!(results.matrix[1][2], 5);
+    assert_eq!(results.matrix[2][1], 2);
+    assert_eq!(results.condorcet_winner, Some(1));
+    assert!(results.cycles.is_empty());
+    assert!(results.skipped_pairs.is_empty());
+    assert_eq!(results.schulze_ranking, vec![1, 0, 2]);
+    assert_eq!(results.ranked_pairs_ranking, vec![1, 0, 2]);
+
+    // counts hold first preferences
+    assert_eq!(contract.get_results(poll_id).unwrap().counts[&2], 2);
+
+    // Rankings are committed to like any other ballot
+    let proof = contract.prove_vote(poll_id, "voter0").unwrap();
+    assert_eq!(proof.choice, VoteChoice::Ranking(vec![1, 0]));
+    assert!(proof.verify(&contract.get_state_root()));
+}
+
+// An exact tie between two options goes to the tie-break policy
+#[test]
+fn test_ranked_tie() {
+    let mut contract = setup_contract();
+    let poll_id = create_ranked_poll(&mut contract, &["A", "B"], CondorcetRule::RankedPairs);
+
+    let mut voter = 0;
+    cast(&mut contract, poll_id, 1, &letters("AB"), &mut voter);
+    cast(&mut contract, poll_id, 1, &letters("BA"), &mut voter);
+
+    let results = contract.get_condorcet_results(poll_id).unwrap();
+    assert_eq!(results.schulze_winners, vec![0, 1]);
+    assert_eq!(results.ranked_pairs_winners, vec![0, 1]);
+
+    contract.close_poll(poll_id, "creator".to_string()).unwrap();
+    assert_eq!(contract.get_winner(poll_id).unwrap(), PollOutcome::Winner { option_index: 0, tie_broken: true });
+}
+
+// Rankings must be non-empty, distinct and in range
+#[test]
+fn test_ranking_validation() {
+    let mut contract = setup_contract();
+    let poll_id = create_ranked_poll(&mut contract, &["A", "B", "C"], CondorcetRule::Schulze);
+
+    for ranking in [vec![], vec![0, 0], vec![3]] {
+        let result = contract.vote_ranked(poll_id, "alice".to_string(), ranking);
+        assert!(matches!(result, Err(ContractError::InvalidOption)));
+    }
+    let result = contract.vote(poll_id, "alice".to_string(), 0);
+    assert!(matches!(result, Err(ContractError::MethodMismatch)));
+
+    // The Condorcet rules already settle every pair, so no majority runoff
+    let result = open_poll(
+        &mut contract,
+        vec!["A", "B", "C"],
+        PollSettings {
+            method: PollMethod::Ranked { rule: CondorcetRule::Schulze },
+            runoff: Some(RunoffConfig { majority_bps: 5000, top_n: 2, duration: 100 }),
+            ..PollSettings::default()
+        },
+    );
+    assert!(matches!(result, Err(ContractError::InvalidSettings)));
+
+    let plurality = contract.create_poll(
+        "creator".to_string(),
+        "Plurality".to_string(),
+        "One choice".to_string(),
+        vec!["Yes", "No"],
+        0,
+        u64::MAX,
+    ).unwrap();
+    assert!(matches!(contract.get_condorcet_results(plurality), Err(ContractError::MethodMismatch)));
+
+    contract.cancel_poll(poll_id, "creator".to_string(), "Duplicate poll".to_string()).unwrap();
+    assert!(matches!(contract.get_condorcet_results(poll_id), Err(ContractError::PollCancelled)));
+}
+
+// The results hash commits to the rankings, not just first preferences
+#[test]
+fn test_results_hash_covers_rankings() {
+    let mut contract_a = setup_contract();
+    let mut contract_b = setup_contract();
+    let poll_a = create_ranked_poll(&mut contract_a, &["A", "B", "C"], CondorcetRule::Schulze);
+    let poll_b = create_ranked_poll(&mut contract_b, &["A", "B", "C"], CondorcetRule::Schulze);
+
+    contract_a.vote_ranked(poll_a, "alice".to_string(), vec![0, 1, 2]).unwrap();
+    contract_b.vote_ranked(poll_b, "alice".to_string(), vec![0, 2, 1]).unwrap();
+
+    assert_eq!(contract_a.get_results(poll_a).unwrap().counts, contract_b.get_results(poll_b).unwrap().counts);
+    assert_ne!(contract_a.get_results_hash(poll_a).unwrap(), contract_b.get_results_hash(poll_b).unwrap());
+}
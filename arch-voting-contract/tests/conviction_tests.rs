@@ -0,0 +1,151 @@
+// Tests for conviction voting pools
+use std::collections::BTreeMap;
+
+use arch_voting_contract::conviction::{decay, DECAY_SCALE};
+use arch_voting_contract::{ContractError, ConvictionConfig, Event, VotingContract};
+
+mod common;
+use common::{setup_manual_contract, START};
+
+fn config(half_life: u64, weights: &[(&str, u64)]) -> ConvictionConfig {
+    ConvictionConfig {
+        half_life,
+        funds: 10_000,
+        max_request_bps: 5000,
+        min_threshold_bps: 200,
+        weights: weights.iter().map(|(wallet, weight)| (wallet.to_string(), *weight)).collect::<BTreeMap<_, _>>(),
+    }
+}
+
+fn create_pool(contract: &mut VotingContract, config: ConvictionConfig) -> Result<u64, ContractError> {
+    contract.create_conviction_pool(
+        "creator".to_string(),
+        "Community Fund".to_string(),
+        "Continuous funding for community projects".to_string(),
+        config,
+    )
+}
+
+// The decay factor halves every half-life and interpolates in between
+#[test]
+fn test_decay_curve() {
+    assert_eq!(decay(0, 100), DECAY_SCALE);
+    assert_eq!(decay(100, 100), DECAY_SCALE / 2);
+    assert_eq!(decay(300, 100), DECAY_SCALE / 8);
+    assert_eq!(decay(6400, 100), 0);
+
+    // 2^(-1/2)
+    let root = decay(50, 100);
+    assert!(root.abs_diff(707_106_781_186_547_524) < 1_000_000_000);
+    assert!(decay(25, 100) > root && decay(75, 100) < root);
+}
+
+// Conviction grows toward the stake, and bigger requests need more of it
+#[test]
+fn test_conviction_growth_and_threshold() {
+    let mut contract = setup_manual_contract();
+    let pool_id = create_pool(&mut contract, config(3600, &[("alice", 600), ("bob", 400)])).unwrap();
+
+    let small = contract
+        .submit_funding_proposal(pool_id, "alice".to_string(), "Docs".to_string(), "writer".to_string(), 1000)
+        .unwrap();
+    let large = contract
+        .submit_funding_proposal(pool_id, "bob".to_string(), "Venue".to_string(), "host".to_string(), 5000)
+        .unwrap();
+
+    contract.stake_conviction(pool_id, small, "alice".to_string(), 300).unwrap();
+    let staked_at = contract.get_conviction_pool(pool_id).unwrap().proposals[small as usize].updated_at;
+
+    // 2% of 1000 weight over (50% - 10%)^2
+    let status = contract.get_conviction_status(pool_id, small, staked_at).unwrap();
+    assert_eq!((status.staked, status.conviction, status.threshold), (300, 0, Some(125)));
+    assert!(!status.passable);
+
+    let status = contract.get_conviction_status(pool_id, small, staked_at + 3600).unwrap();
+    assert_eq!(status.conviction, 150);
+    assert!(status.passable);
+    let status = contract.get_conviction_status(pool_id, small, staked_at + 7200).unwrap();
+    assert_eq!(status.conviction, 225);
+
+    // A near-zero request needs 200 * (10000 / 5000)^2 = 800 bps of all weight
+    assert_eq!(contract.get_conviction_pool(pool_id).unwrap().threshold(0), Some(80));
+
+    // Half the funds is the most any proposal can win
+    assert_eq!(contract.get_conviction_status(pool_id, large, staked_at).unwrap().threshold, None);
+
+    let result = contract.execute_funding_proposal(pool_id, small);
+    assert!(matches!(result, Err(ContractError::ThresholdNotReached)));
+}
+
+// A passing proposal is paid once and releases the stake behind it
+#[test]
+fn test_funding_proposal_passes() {
+    let mut contract = setup_manual_contract();
+    let pool_id = create_pool(&mut contract, config(1, &[("alice", 100)])).unwrap();
+    let proposal_id = contract
+        .submit_funding_proposal(pool_id, "alice".to_string(), "Docs".to_string(), "writer".to_string(), 100)
+        .unwrap();
+
+    contract.stake_conviction(pool_id, proposal_id, "alice".to_string(), 100).unwrap();
+    contract.set_time(START + 1).unwrap();
+    assert!(matches!(contract.set_time(START), Err(ContractError::InvalidTimeRange)));
+    let mut live = VotingContract::new("owner".to_string());
+    assert!(matches!(live.set_time(START), Err(ContractError::SystemClock)));
+    contract.execute_funding_proposal(pool_id, proposal_id).unwrap();
+
+    let pool = contract.get_conviction_pool(pool_id).unwrap();
+    assert_eq!(pool.available_funds, 9900);
+    assert!(pool.proposals[0].passed_at.is_some());
+    assert_eq!(pool.staked_by("alice"), 0);
+    assert_eq!(contract.get_events().last(), Some(&Event::FundingProposalPassed {
+        pool_id,
+        proposal_id,
+        beneficiary: "writer".to_string(),
+        amount: 100,
+    }));
+
+    let result = contract.execute_funding_proposal(pool_id, proposal_id);
+    assert!(matches!(result, Err(ContractError::ProposalClosed)));
+    let result = contract.stake_conviction(pool_id, proposal_id, "alice".to_string(), 10);
+    assert!(matches!(result, Err(ContractError::ProposalClosed)));
+}
+
+// Stakes are limited by weight, and pools and requests are validated
+#[test]
+fn test_conviction_validation() {
+    let mut contract = setup_manual_contract();
+
+    let result = create_pool(&mut contract, config(0, &[("alice", 100)]));
+    assert!(matches!(result, Err(ContractError::InvalidSettings)));
+    let result = create_pool(&mut contract, config(60, &[("alice", u64::MAX), ("bob", 1)]));
+    assert!(matches!(result, Err(ContractError::InvalidSettings)));
+
+    let pool_id = create_pool(&mut contract, config(60, &[("alice", 100)])).unwrap();
+    let result = contract.submit_funding_proposal(pool_id, "alice".to_string(), "Big".to_string(), "x".to_string(), 10_001);
+    assert!(matches!(result, Err(ContractError::InsufficientFunds)));
+    let result = contract.submit_funding_proposal(pool_id, "alice".to_string(), " ".to_string(), "x".to_string(), 100);
+    assert!(matches!(result, Err(ContractError::EmptyLabel)));
+    let result = contract.submit_funding_proposal(pool_id, "alice".to_string(), "t".repeat(129), "x".to_string(), 100);
+    assert!(matches!(result, Err(ContractError::OptionTooLarge)));
+    let proposal_id = contract
+        .submit_funding_proposal(pool_id, "alice".to_string(), "Docs".to_string(), "writer".to_string(), 100)
+        .unwrap();
+
+    contract.stake_conviction(pool_id, proposal_id, "alice".to_string(), 60).unwrap();
+    let result = contract.stake_conviction(pool_id, proposal_id, "alice".to_string(), 41);
+    assert!(matches!(result, Err(ContractError::InsufficientWeight)));
+    let result = contract.unstake_conviction(pool_id, proposal_id, "alice".to_string(), 61);
+    assert!(matches!(result, Err(ContractError::InsufficientWeight)));
+    let result = contract.stake_conviction(pool_id, proposal_id, "mallory".to_string(), 1);
+    assert!(matches!(result, Err(ContractError::NotEligible)));
+    let result = contract.stake_conviction(pool_id, 7, "alice".to_string(), 1);
+    assert!(matches!(result, Err(ContractError::FundingProposalNotFound)));
+
+    contract.unstake_conviction(pool_id, proposal_id, "alice".to_string(), 60).unwrap();
+    assert_eq!(contract.get_conviction_pool(pool_id).unwrap().staked_by("alice"), 0);
+
+    contract.close_conviction_pool(pool_id, "creator".to_string()).unwrap();
+    let result = contract.stake_conviction(pool_id, proposal_id, "alice".to_string(), 1);
+    assert!(matches!(result, Err(ContractError::PollNotActive)));
+    assert!(matches!(contract.get_conviction_pool(99), Err(ContractError::PoolNotFound)));
+}
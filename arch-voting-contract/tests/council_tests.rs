@@ -0,0 +1,206 @@
+// Tests for the multisig admin council
+use arch_voting_contract::{
+    AdminAction, ConfigParam, ContractError, Event, Role, VotingContract,
+};
+
+mod common;
+use common::setup_contract;
+
+fn council_contract() -> (VotingContract, u64) {
+    let mut contract = setup_contract();
+
+    let poll_id = contract.create_poll(
+        "creator".to_string(),
+        "Council Poll".to_string(),
+        "Poll used for council tests".to_string(),
+        vec!["Yes".to_string(), "No".to_string()],
+        0,
+        u64::MAX,
+    ).unwrap();
+
+    let members = vec!["alice".to_string(), "bob".to_string(), "carol".to_string()];
+    contract.set_council(members, 2, "owner".to_string()).unwrap();
+
+    (contract, poll_id)
+}
+
+// Council membership and threshold are validated
+#[test]
+fn test_invalid_council() {
+    let mut contract = VotingContract::new("owner".to_string());
+
+    let result = contract.set_council(vec!["a".to_string(), "b".to_string()], 3, "owner".to_string());
+    assert!(matches!(result, Err(ContractError::InvalidCouncil)));
+
+    let result = contract.set_council(vec!["a".to_string(), "a".to_string()], 1, "owner".to_string());
+    assert!(matches!(result, Err(ContractError::InvalidCouncil)));
+
+    let result = contract.set_council(vec!["a".to_string()], 1, "a".to_string());
+    assert!(matches!(result, Err(ContractError::Unauthorized)));
+
+    let result = contract.propose_admin_action(AdminAction::ClosePoll { poll_id: 0 }, "a".to_string());
+    assert!(matches!(result, Err(ContractError::NoCouncil)));
+}
+
+// With a council in place, the owner can no longer act alone
+#[test]
+fn test_privileged_actions_require_council() {
+    let (mut contract, poll_id) = council_contract();
+
+    let result = contract.close_poll(poll_id, "owner".to_string());
+    assert!(matches!(result, Err(ContractError::CouncilApprovalRequired)));
+
+    let result = contract.grant_role(Role::Moderator, "x".to_string(), "owner".to_string());
+    assert!(matches!(result, Err(ContractError::CouncilApprovalRequired)));
+
+    let result = contract.set_config(ConfigParam::MaxOptions(8), "owner".to_string());
+    assert!(matches!(result, Err(ContractError::CouncilApprovalRequired)));
+
+    let result = contract.set_council(vec!["owner".to_string()], 1, "owner".to_string());
+    assert!(matches!(result, Err(ContractError::CouncilApprovalRequired)));
+
+    let result = contract.propose_owner("nominee".to_string(), "owner".to_string());
+    assert!(matches!(result, Err(ContractError::CouncilApprovalRequired)));
+    assert_eq!(contract.get_pending_owner(), None);
+
+    // Non-privileged actions are unaffected
+    assert!(contract.get_poll(poll_id).unwrap().active);
+    contract.close_poll(poll_id, "creator".to_string()).unwrap();
+}
+
+// An action runs once the threshold is reached, and only once
+#[test]
+fn test_threshold_execution() {
+    let (mut contract, poll_id) = council_contract();
+
+    let result = contract.propose_admin_action(AdminAction::ClosePoll { poll_id }, "mallory".to_string());
+    assert!(matches!(result, Err(ContractError::Unauthorized)));
+
+    let proposal_id = contract.propose_admin_action(AdminAction::ClosePoll { poll_id }, "alice".to_string()).unwrap();
+    assert!(contract.get_poll(poll_id).unwrap().active);
+
+    let result = contract.approve_admin_action(proposal_id, "alice".to_string());
+    assert!(matches!(result, Err(ContractError::AlreadyApproved)));
+
+    assert!(contract.approve_admin_action(proposal_id, "bob".to_string()).unwrap());
+    assert!(!contract.get_poll(poll_id).unwrap().active);
+
+    let proposal = contract.get_admin_proposal(proposal_id).unwrap();
+    assert!(proposal.executed);
+    assert_eq!(proposal.approvals, vec!["alice".to_string(), "bob".to_string()]);
+
+    let result = contract.approve_admin_action(proposal_id, "carol".to_string());
+    assert!(matches!(result, Err(ContractError::ProposalAlreadyExecuted)));
+
+    assert_eq!(contract.get_events().last(), Some(&Event::AdminActionExecuted { proposal_id }));
+}
+
+// Role and config changes go through the council
+#[test]
+fn test_council_role_and_config_changes() {
+    let (mut contract, _) = council_contract();
+
+    let grant = AdminAction::GrantRole { role: Role::Moderator, account: "mod".to_string() };
+    let proposal_id = contract.propose_admin_action(grant, "bob".to_string()).unwrap();
+    contract.approve_admin_action(proposal_id, "carol".to_string()).unwrap();
+    assert!(contract.has_role(Role::Moderator, "mod"));
+
+    let config = AdminAction::SetConfig(ConfigParam::MaxOptions(3));
+    let proposal_id = contract.propose_admin_action(config, "alice".to_string()).unwrap();
+    contract.approve_admin_action(proposal_id, "carol".to_string()).unwrap();
+    assert_eq!(contract.get_config().max_options, 3);
+
+    let result = contract.set_balance("alice".to_string(), 100, "owner".to_string());
+    assert!(matches!(result, Err(ContractError::CouncilApprovalRequired)));
+    let fund = AdminAction::SetBalance { wallet_address: "alice".to_string(), balance: 100 };
+    let proposal_id = contract.propose_admin_action(fund, "alice".to_string()).unwrap();
+    contract.approve_admin_action(proposal_id, "bob".to_string()).unwrap();
+    assert_eq!(contract.get_balance("alice"), 100);
+
+    // A failing action leaves the proposal pending
+    let invalid = AdminAction::SetConfig(ConfigParam::MaxOptions(1));
+    let proposal_id = contract.propose_admin_action(invalid, "alice".to_string()).unwrap();
+    let result = contract.approve_admin_action(proposal_id, "bob".to_string());
+    assert!(matches!(result, Err(ContractError::InvalidConfig)));
+    assert!(!contract.get_admin_proposal(proposal_id).unwrap().executed);
+    assert_eq!(contract.get_config().max_options, 3);
+}
+
+// Other creators' polls are cancelled through the council, with a reason
+#[test]
+fn test_council_cancels_poll() {
+    let (mut contract, poll_id) = council_contract();
+
+    let result = contract.cancel_poll(poll_id, "owner".to_string(), "Fraud".to_string());
+    assert!(matches!(result, Err(ContractError::CouncilApprovalRequired)));
+
+    let blank = AdminAction::CancelPoll { poll_id, reason: " ".to_string() };
+    let proposal_id = contract.propose_admin_action(blank, "alice".to_string()).unwrap();
+    let result = contract.approve_admin_action(proposal_id, "bob".to_string());
+    assert!(matches!(result, Err(ContractError::InvalidReason)));
+    assert!(!contract.get_poll(poll_id).unwrap().is_cancelled());
+
+    let cancel = AdminAction::CancelPoll { poll_id, reason: "Fraud".to_string() };
+    let proposal_id = contract.propose_admin_action(cancel.clone(), "alice".to_string()).unwrap();
+    contract.approve_admin_action(proposal_id, "bob".to_string()).unwrap();
+
+    let poll = contract.get_poll(poll_id).unwrap();
+    assert!(!poll.active);
+    assert_eq!(poll.cancellation.as_ref().unwrap().cancelled_by, "alice");
+    assert!(contract.get_events().contains(&Event::PollCancelled {
+        poll_id,
+        cancelled_by: "alice".to_string(),
+        reason: "Fraud".to_string(),
+    }));
+
+    let proposal_id = contract.propose_admin_action(cancel, "alice".to_string()).unwrap();
+    let result = contract.approve_admin_action(proposal_id, "carol".to_string());
+    assert!(matches!(result, Err(ContractError::PollCancelled)));
+}
+
+// Once a council exists, only the council can nominate an owner or withdraw
+// the nomination
+#[test]
+fn test_council_ownership_transfer() {
+    let (mut contract, _) = council_contract();
+
+    let nominate = AdminAction::TransferOwnership { new_owner: "dao".to_string() };
+    let proposal_id = contract.propose_admin_action(nominate, "alice".to_string()).unwrap();
+    contract.approve_admin_action(proposal_id, "bob".to_string()).unwrap();
+    assert_eq!(contract.get_pending_owner(), Some("dao"));
+
+    let result = contract.cancel_ownership_transfer("owner".to_string());
+    assert!(matches!(result, Err(ContractError::CouncilApprovalRequired)));
+    assert_eq!(contract.get_pending_owner(), Some("dao"));
+
+    let withdraw = AdminAction::CancelOwnershipTransfer;
+    let proposal_id = contract.propose_admin_action(withdraw.clone(), "alice".to_string()).unwrap();
+    contract.approve_admin_action(proposal_id, "carol".to_string()).unwrap();
+    assert_eq!(contract.get_pending_owner(), None);
+    assert!(contract.get_events().contains(&Event::OwnershipTransferCancelled {
+        owner: "owner".to_string(),
+        proposed_owner: "dao".to_string(),
+    }));
+
+    let proposal_id = contract.propose_admin_action(withdraw, "alice".to_string()).unwrap();
+    let result = contract.approve_admin_action(proposal_id, "bob".to_string());
+    assert!(matches!(result, Err(ContractError::NoPendingTransfer)));
+}
+
+// Approvals from members a later SetCouncil removed no longer count
+#[test]
+fn test_removed_members_approvals_lapse() {
+    let (mut contract, _) = council_contract();
+
+    let grant = AdminAction::GrantRole { role: Role::Admin, account: "mallory".to_string() };
+    let pending = contract.propose_admin_action(grant, "alice".to_string()).unwrap();
+
+    let replace = AdminAction::SetCouncil { members: vec!["bob".to_string(), "dave".to_string()], threshold: 2 };
+    let proposal_id = contract.propose_admin_action(replace, "bob".to_string()).unwrap();
+    contract.approve_admin_action(proposal_id, "carol".to_string()).unwrap();
+
+    assert!(!contract.approve_admin_action(pending, "dave".to_string()).unwrap());
+    assert!(!contract.has_role(Role::Admin, "mallory"));
+    assert!(contract.approve_admin_action(pending, "bob".to_string()).unwrap());
+    assert!(contract.has_role(Role::Admin, "mallory"));
+}
@@ -0,0 +1,112 @@
+// Tests for cumulative voting polls
+use std::collections::BTreeMap;
+
+use arch_voting_contract::{
+    ContractError, CumulativeConfig, PollMethod, PollOutcome, PollSettings, RunoffConfig, VoteChoice,
+    VotingContract,
+};
+
+mod common;
+use common::{open_method_poll, open_poll, setup_contract};
+
+fn create_poll(contract: &mut VotingContract, config: CumulativeConfig) -> Result<u64, ContractError> {
+    open_method_poll(contract, vec!["Nominee A", "Nominee B", "Nominee C"], PollMethod::Cumulative(config))
+}
+
+// Points add up per option and may all go to one option
+#[test]
+fn test_cumulative_tally() {
+    let mut contract = setup_contract();
+    let poll_id = create_poll(&mut contract, CumulativeConfig { points: 3, exact: false, weights: None }).unwrap();
+
+    contract.vote_points(poll_id, "alice".to_string(), vec![0, 0, 3]).unwrap();
+    contract.vote_points(poll_id, "bob".to_string(), vec![1, 2, 0]).unwrap();
+    contract.vote_points(poll_id, "carol".to_string(), vec![2, 0, 0]).unwrap();
+
+    let results = contract.get_results(poll_id).unwrap();
+    assert_eq!(results.total_votes, 3);
+    let points: Vec<u64> = (0..3).map(|index| results.counts[&index]).collect();
+    assert_eq!(points, vec![3, 2, 3]);
+
+    let ordered = contract.get_ordered_results(poll_id).unwrap();
+    let shares: Vec<u32> = ordered.options.iter().map(|o| o.share_bps).collect();
+    assert_eq!(shares, vec![3750, 2500, 3750]);
+
+    contract.close_poll(poll_id, "creator".to_string()).unwrap();
+    assert_eq!(contract.get_winner(poll_id).unwrap(), PollOutcome::Winner { option_index: 0, tie_broken: true });
+
+    let proof = contract.prove_vote(poll_id, "bob").unwrap();
+    assert_eq!(proof.choice, VoteChoice::Points(vec![1, 2, 0]));
+    assert!(proof.verify(&contract.get_state_root()));
+}
+
+// Budgets scale with wallet weight and can be required in full
+#[test]
+fn test_weighted_budgets() {
+    let mut contract = setup_contract();
+    let weights = BTreeMap::from([("fund".to_string(), 100), ("holder".to_string(), 5)]);
+    let poll_id = create_poll(&mut contract, CumulativeConfig { points: 3, exact: true, weights: Some(weights) }).unwrap();
+
+    contract.vote_points(poll_id, "fund".to_string(), vec![300, 0, 0]).unwrap();
+
+    let result = contract.vote_points(poll_id, "holder".to_string(), vec![10, 6, 0]);
+    assert!(matches!(result, Err(ContractError::OverBudget)));
+    let result = contract.vote_points(poll_id, "holder".to_string(), vec![10, 4, 0]);
+    assert!(matches!(result, Err(ContractError::UnderBudget)));
+    contract.vote_points(poll_id, "holder".to_string(), vec![0, 10, 5]).unwrap();
+
+    // Wallets without a weight cannot vote
+    let result = contract.vote_points(poll_id, "stranger".to_string(), vec![1, 0, 0]);
+    assert!(matches!(result, Err(ContractError::NotEligible)));
+
+    let results = contract.get_results(poll_id).unwrap();
+    assert_eq!(results.counts[&0], 300);
+    assert_eq!(results.counts[&1], 10);
+    assert_eq!(results.counts[&2], 5);
+}
+
+// Malformed ballots and budgets are rejected
+#[test]
+fn test_cumulative_validation() {
+    let mut contract = setup_contract();
+
+    let result = create_poll(&mut contract, CumulativeConfig { points: 0, exact: false, weights: None });
+    assert!(matches!(result, Err(ContractError::InvalidSettings)));
+    let weights = BTreeMap::from([("whale".to_string(), u64::MAX)]);
+    let result = create_poll(&mut contract, CumulativeConfig { points: 2, exact: false, weights: Some(weights) });
+    assert!(matches!(result, Err(ContractError::InvalidSettings)));
+    let result = open_poll(
+        &mut contract,
+        vec!["Nominee A", "Nominee B"],
+        PollSettings {
+            method: PollMethod::Cumulative(CumulativeConfig { points: 5, exact: false, weights: None }),
+            runoff: Some(RunoffConfig { majority_bps: 5000, top_n: 2, duration: 100 }),
+            ..PollSettings::default()
+        },
+    );
+    assert!(matches!(result, Err(ContractError::InvalidSettings)));
+
+    let poll_id = create_poll(&mut contract, CumulativeConfig { points: 5, exact: false, weights: None }).unwrap();
+    let result = contract.vote_points(poll_id, "alice".to_string(), vec![1, 1]);
+    assert!(matches!(result, Err(ContractError::InvalidOption)));
+    let result = contract.vote_points(poll_id, "alice".to_string(), vec![0, 0, 0]);
+    assert!(matches!(result, Err(ContractError::InvalidOption)));
+    let result = contract.vote_points(poll_id, "alice".to_string(), vec![u64::MAX, 1, 0]);
+    assert!(matches!(result, Err(ContractError::OverBudget)));
+    let result = contract.vote(poll_id, "alice".to_string(), 0);
+    assert!(matches!(result, Err(ContractError::MethodMismatch)));
+
+    // Staying under the budget is fine unless the poll requires it in full
+    contract.vote_points(poll_id, "alice".to_string(), vec![1, 0, 0]).unwrap();
+
+    // Budgets that fit on their own can still overflow an option's total
+    let half = 1 << 63;
+    let weights = BTreeMap::from([("whale".to_string(), half), ("orca".to_string(), half)]);
+    let poll_id = create_poll(&mut contract, CumulativeConfig { points: 1, exact: false, weights: Some(weights) }).unwrap();
+    contract.vote_points(poll_id, "whale".to_string(), vec![half, 0, 0]).unwrap();
+    let result = contract.vote_points(poll_id, "orca".to_string(), vec![half, 0, 0]);
+    assert!(matches!(result, Err(ContractError::TallyOverflow)));
+    assert!(!contract.has_voted(poll_id, "orca").unwrap());
+    assert_eq!(contract.get_results(poll_id).unwrap().total_votes, 1);
+    contract.vote_points(poll_id, "orca".to_string(), vec![0, half, 0]).unwrap();
+}
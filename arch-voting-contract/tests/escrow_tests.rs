@@ -0,0 +1,167 @@
+// Tests for vote-escrow locks and escrow-weighted polls
+use arch_voting_contract::{
+    ContractError, Lock, LockHistory, PollMethod, PollSettings, VotingContract, VotingPower,
+};
+
+mod common;
+use common::{setup_manual_contract, START};
+
+const MAX_LOCK: u64 = 4 * 365 * 86400;
+
+// Give alice and bob a balance they may lock
+fn fund(contract: &mut VotingContract, balance: u64) {
+    for wallet in ["alice", "bob"] {
+        contract.set_balance(wallet.to_string(), balance, "owner".to_string()).unwrap();
+    }
+}
+
+fn create_escrow_poll(contract: &mut VotingContract, start_time: u64, method: PollMethod) -> Result<u64, ContractError> {
+    contract.create_poll_with_settings(
+        "creator".to_string(),
+        "Treasury Vote".to_string(),
+        "Weighted by locked balance".to_string(),
+        vec!["Approve", "Reject"],
+        start_time,
+        u64::MAX,
+        PollSettings {
+            method,
+            voting_power: VotingPower::Escrow,
+            ..PollSettings::default()
+        },
+    )
+}
+
+// Power falls linearly to zero at unlock, and history answers past lookups
+#[test]
+fn test_power_decays_linearly() {
+    let lock = Lock { amount: 1000, unlock_time: 400, max_duration: 400 };
+    assert_eq!(lock.power_at(0), 1000);
+    assert_eq!(lock.power_at(100), 750);
+    assert_eq!(lock.power_at(400), 0);
+    assert_eq!(lock.power_at(500), 0);
+
+    let longer = Lock { amount: 1000, unlock_time: 800, max_duration: 400 };
+    let mut history = LockHistory::default();
+    history.push(10, Some(lock));
+    history.push(20, Some(lock));
+    history.push(20, Some(longer));
+    history.push(30, None);
+
+    assert_eq!(history.checkpoints.len(), 3);
+    assert_eq!(history.at(5), None);
+    assert_eq!(history.at(15), Some(&lock));
+    assert_eq!(history.at(20), Some(&longer));
+    assert_eq!(history.power_at(25), 1000);
+    assert_eq!(history.power_at(30), 0);
+    assert_eq!(history.current(), None);
+}
+
+// Votes count for the wallet's power at the snapshot; locks made after it
+// earn nothing
+#[test]
+fn test_escrow_weighted_poll() {
+    let mut contract = setup_manual_contract();
+    fund(&mut contract, 1000);
+    contract.create_lock("alice".to_string(), 1000, START + MAX_LOCK).unwrap();
+    contract.create_lock("bob".to_string(), 1000, START + MAX_LOCK / 4).unwrap();
+
+    contract.set_time(START + MAX_LOCK / 8).unwrap();
+    let start_time = START + MAX_LOCK / 8;
+    let poll_id = create_escrow_poll(&mut contract, start_time, PollMethod::Plurality).unwrap();
+    let alice = contract.get_voting_power("alice", start_time);
+    let bob = contract.get_voting_power("bob", start_time);
+    assert_eq!((alice, bob), (875, 125));
+
+    contract.vote(poll_id, "alice".to_string(), 0).unwrap();
+    contract.vote(poll_id, "bob".to_string(), 1).unwrap();
+    let result = contract.vote(poll_id, "carol".to_string(), 1);
+    assert!(matches!(result, Err(ContractError::NoVotingPower)));
+
+    let results = contract.get_results(poll_id).unwrap();
+    assert_eq!(results.total_votes, 2);
+    assert_eq!(results.counts[&0], alice);
+    assert_eq!(results.counts[&1], bob);
+
+    // Buying and locking after the snapshot does not help
+    let early = create_escrow_poll(&mut contract, START - 10, PollMethod::Plurality).unwrap();
+    let result = contract.vote(early, "alice".to_string(), 0);
+    assert!(matches!(result, Err(ContractError::NoVotingPower)));
+}
+
+// Weighted counts never overflow, and totals over them stay exact
+#[test]
+fn test_escrow_tally_overflow() {
+    let mut contract = setup_manual_contract();
+    fund(&mut contract, u64::MAX);
+    contract.create_lock("alice".to_string(), u64::MAX, START + MAX_LOCK).unwrap();
+    contract.create_lock("bob".to_string(), u64::MAX, START + MAX_LOCK).unwrap();
+
+    let poll_id = create_escrow_poll(&mut contract, START, PollMethod::Plurality).unwrap();
+    contract.vote(poll_id, "alice".to_string(), 0).unwrap();
+    let result = contract.vote(poll_id, "bob".to_string(), 0);
+    assert!(matches!(result, Err(ContractError::TallyOverflow)));
+    assert!(!contract.has_voted(poll_id, "bob").unwrap());
+
+    contract.vote(poll_id, "bob".to_string(), 1).unwrap();
+    let ordered = contract.get_ordered_results(poll_id).unwrap();
+    assert_eq!(ordered.options[0].share_bps, 5000);
+    assert_eq!(ordered.options[1].share_bps, 5000);
+    assert_eq!(contract.get_detailed_results(poll_id).unwrap()["Approve"].1, 50.0);
+}
+
+// Locks are bounded, can only grow, and are withdrawn after expiry
+#[test]
+fn test_lock_lifecycle() {
+    let mut contract = setup_manual_contract();
+    fund(&mut contract, 15);
+    let result = contract.create_lock("alice".to_string(), 0, START + 100);
+    assert!(matches!(result, Err(ContractError::InvalidLock)));
+    let result = contract.create_lock("alice".to_string(), 10, START + MAX_LOCK + 10);
+    assert!(matches!(result, Err(ContractError::InvalidLock)));
+
+    contract.create_lock("alice".to_string(), 10, START + 1000).unwrap();
+    let result = contract.create_lock("alice".to_string(), 10, START + 2000);
+    assert!(matches!(result, Err(ContractError::LockExists)));
+    let result = contract.extend_lock("alice".to_string(), START + 500);
+    assert!(matches!(result, Err(ContractError::InvalidLock)));
+    contract.extend_lock("alice".to_string(), START + 2000).unwrap();
+    contract.increase_lock_amount("alice".to_string(), 5).unwrap();
+    assert_eq!(contract.get_lock("alice").unwrap().amount, 15);
+    assert_eq!(contract.get_free_balance("alice"), 0);
+    assert_eq!(contract.get_lock("alice").unwrap().unlock_time, START + 2000);
+    let result = contract.withdraw_lock("alice".to_string());
+    assert!(matches!(result, Err(ContractError::LockNotExpired)));
+
+    contract.create_lock("bob".to_string(), 7, START + 1).unwrap();
+    contract.set_time(START + 1).unwrap();
+    assert_eq!(contract.withdraw_lock("bob".to_string()).unwrap(), 7);
+    assert!(matches!(contract.get_lock("bob"), Err(ContractError::LockNotFound)));
+
+    // Only plurality ballots can be weighted
+    let result = create_escrow_poll(&mut contract, 0, PollMethod::Score { min: 0, max: 5 });
+    assert!(matches!(result, Err(ContractError::InvalidSettings)));
+}
+
+// Wallets can only lock the balance admins gave them
+#[test]
+fn test_locks_need_balance() {
+    let mut contract = setup_manual_contract();
+    let result = contract.create_lock("alice".to_string(), u64::MAX, START + MAX_LOCK);
+    assert!(matches!(result, Err(ContractError::InsufficientBalance)));
+    assert_eq!(contract.get_voting_power("alice", START), 0);
+
+    let result = contract.set_balance("alice".to_string(), 100, "alice".to_string());
+    assert!(matches!(result, Err(ContractError::Unauthorized)));
+    fund(&mut contract, 100);
+    let result = contract.create_lock("alice".to_string(), 101, START + MAX_LOCK);
+    assert!(matches!(result, Err(ContractError::InsufficientBalance)));
+
+    contract.create_lock("alice".to_string(), 60, START + MAX_LOCK).unwrap();
+    assert_eq!((contract.get_balance("alice"), contract.get_free_balance("alice")), (100, 40));
+    let result = contract.increase_lock_amount("alice".to_string(), 41);
+    assert!(matches!(result, Err(ContractError::InsufficientBalance)));
+    let result = contract.set_balance("alice".to_string(), 59, "owner".to_string());
+    assert!(matches!(result, Err(ContractError::InsufficientBalance)));
+    contract.increase_lock_amount("alice".to_string(), 40).unwrap();
+    assert_eq!(contract.get_voting_power("alice", START), 100);
+}
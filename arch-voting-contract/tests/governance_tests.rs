@@ -0,0 +1,257 @@
+// Tests for executable governance proposals
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+use arch_voting_contract::{
+    AdminAction, CallbackEffect, ConfigParam, ContractError, Event, Role, VotingContract,
+};
+
+mod common;
+use common::{setup_manual_contract, START};
+
+// Four wallets vote on proposals, so two votes meet the default quorum
+fn proposal_contract() -> VotingContract {
+    let mut contract = setup_manual_contract();
+    let electorate = (0..4).map(|voter| format!("voter{}", voter)).collect();
+    contract.set_config(ConfigParam::ProposalElectorate(electorate), "owner".to_string()).unwrap();
+    contract
+}
+
+fn create_proposal(contract: &mut VotingContract, actions: Vec<AdminAction>) -> Result<u64, ContractError> {
+    contract.create_executable_proposal(
+        "owner".to_string(),
+        "Hand over to the DAO".to_string(),
+        "Runs if approved".to_string(),
+        actions,
+    )
+}
+
+// Cast approvals and rejections, then let the poll run out and finalize it
+fn decide(contract: &mut VotingContract, poll_id: u64, approve: usize, reject: usize) {
+    for voter in 0..approve + reject {
+        let option = if voter < approve { 0 } else { 1 };
+        contract.vote(poll_id, format!("voter{}", voter), option).unwrap();
+    }
+    let end_time = contract.get_poll(poll_id).unwrap().end_time;
+    contract.set_time(end_time + 1).unwrap();
+    contract.finalize_poll(poll_id).unwrap();
+}
+
+// A passed proposal runs every action at finalization, exactly once
+#[test]
+fn test_passed_proposal_runs_once() {
+    let mut contract = proposal_contract();
+    let calls = Rc::new(RefCell::new(Vec::new()));
+    let recorded = Rc::clone(&calls);
+    contract.register_callback(
+        "treasury".to_string(),
+        Box::new(move |payload: &[u8]| {
+            let (recorded, payload) = (Rc::clone(&recorded), payload.to_vec());
+            Ok(Box::new(move || recorded.borrow_mut().push(payload)) as CallbackEffect)
+        }),
+        "owner".to_string(),
+    ).unwrap();
+
+    let poll_id = create_proposal(&mut contract, vec![
+        AdminAction::SetConfig(ConfigParam::MaxOptions(10)),
+        AdminAction::GrantRole { role: Role::Moderator, account: "mod".to_string() },
+        AdminAction::Callback { name: "treasury".to_string(), payload: vec![1, 2, 3] },
+        AdminAction::TransferOwnership { new_owner: "dao".to_string() },
+    ]).unwrap();
+    let poll = contract.get_poll(poll_id).unwrap();
+    assert_eq!(poll.option_labels(), vec!["Approve", "Reject"]);
+    assert_eq!((poll.start_time, poll.end_time), (START, START + 3 * 86400));
+    assert_eq!(contract.get_executable_proposal(poll_id).unwrap().quorum, 2);
+
+    decide(&mut contract, poll_id, 2, 1);
+
+    assert_eq!(contract.get_config().max_options, 10);
+    assert!(contract.has_role(Role::Moderator, "mod"));
+    assert_eq!(*calls.borrow(), vec![vec![1, 2, 3]]);
+    assert_eq!(contract.get_owner(), "owner");
+    assert_eq!(contract.get_pending_owner(), Some("dao"));
+    assert!(contract.get_executable_proposal(poll_id).unwrap().executed_at.is_some());
+    assert_eq!(contract.get_events().last(), Some(&Event::ProposalExecuted { poll_id }));
+
+    let result = contract.execute_proposal(poll_id);
+    assert!(matches!(result, Err(ContractError::ProposalAlreadyExecuted)));
+    assert_eq!(calls.borrow().len(), 1);
+
+    // The nominee takes over only by accepting
+    contract.accept_ownership("dao".to_string()).unwrap();
+    assert_eq!(contract.get_owner(), "dao");
+}
+
+// A failing action rolls back the others, and rejected proposals never run
+#[test]
+fn test_failed_and_rejected_proposals() {
+    let mut contract = proposal_contract();
+    contract.register_callback(
+        "broken".to_string(),
+        Box::new(|_: &[u8]| Err(ContractError::InvalidPayload)),
+        "owner".to_string(),
+    ).unwrap();
+
+    let poll_id = create_proposal(&mut contract, vec![
+        AdminAction::GrantRole { role: Role::Moderator, account: "mod".to_string() },
+        AdminAction::Callback { name: "broken".to_string(), payload: Vec::new() },
+    ]).unwrap();
+    decide(&mut contract, poll_id, 2, 0);
+
+    assert!(!contract.has_role(Role::Moderator, "mod"));
+    assert_eq!(contract.get_executable_proposal(poll_id).unwrap().executed_at, None);
+    assert_eq!(contract.get_events().last(), Some(&Event::ProposalExecutionFailed { poll_id }));
+    assert!(matches!(contract.execute_proposal(poll_id), Err(ContractError::InvalidPayload)));
+
+    let poll_id = create_proposal(&mut contract, vec![
+        AdminAction::GrantRole { role: Role::Moderator, account: "mod".to_string() },
+        AdminAction::SetConfig(ConfigParam::MaxOptions(1)),
+    ]).unwrap();
+    decide(&mut contract, poll_id, 2, 0);
+    assert!(!contract.has_role(Role::Moderator, "mod"));
+    assert!(matches!(contract.execute_proposal(poll_id), Err(ContractError::InvalidConfig)));
+
+    // A tie does not pass, and neither does a vote short of the quorum
+    let transfer = vec![AdminAction::TransferOwnership { new_owner: "dao".to_string() }];
+    let poll_id = create_proposal(&mut contract, transfer.clone()).unwrap();
+    decide(&mut contract, poll_id, 1, 1);
+    assert!(matches!(contract.execute_proposal(poll_id), Err(ContractError::ProposalRejected)));
+
+    let poll_id = create_proposal(&mut contract, transfer).unwrap();
+    decide(&mut contract, poll_id, 1, 0);
+    assert!(matches!(contract.execute_proposal(poll_id), Err(ContractError::ProposalRejected)));
+    assert_eq!(contract.get_pending_owner(), None);
+}
+
+// Every callback is prepared before any effect is applied, so a failing
+// callback leaves earlier ones unapplied and a retry applies each once
+#[test]
+fn test_callbacks_are_all_or_nothing() {
+    let mut contract = proposal_contract();
+    let runs = Rc::new(RefCell::new(Vec::new()));
+    let broken = Rc::new(Cell::new(true));
+    for name in ["first", "second"] {
+        let (runs, broken) = (Rc::clone(&runs), Rc::clone(&broken));
+        contract.register_callback(
+            name.to_string(),
+            Box::new(move |_: &[u8]| {
+                if name == "second" && broken.get() {
+                    return Err(ContractError::InvalidPayload);
+                }
+                let runs = Rc::clone(&runs);
+                Ok(Box::new(move || runs.borrow_mut().push(name)) as CallbackEffect)
+            }),
+            "owner".to_string(),
+        ).unwrap();
+    }
+
+    let poll_id = create_proposal(&mut contract, vec![
+        AdminAction::Callback { name: "first".to_string(), payload: Vec::new() },
+        AdminAction::Callback { name: "second".to_string(), payload: Vec::new() },
+    ]).unwrap();
+    decide(&mut contract, poll_id, 2, 0);
+    assert!(runs.borrow().is_empty());
+    assert!(matches!(contract.execute_proposal(poll_id), Err(ContractError::InvalidPayload)));
+    assert!(runs.borrow().is_empty());
+
+    broken.set(false);
+    contract.execute_proposal(poll_id).unwrap();
+    assert!(matches!(contract.execute_proposal(poll_id), Err(ContractError::ProposalAlreadyExecuted)));
+    assert_eq!(*runs.borrow(), vec!["first", "second"]);
+}
+
+// Only admins propose actions, proposals must be well-formed, and their polls
+// follow the configured rules
+#[test]
+fn test_proposal_validation() {
+    let mut contract = setup_manual_contract();
+    let actions = vec![AdminAction::TransferOwnership { new_owner: "dao".to_string() }];
+    assert!(matches!(create_proposal(&mut contract, actions.clone()), Err(ContractError::InvalidConfig)));
+
+    let mut contract = proposal_contract();
+    let result = contract.create_executable_proposal(
+        "mallory".to_string(),
+        "Take over".to_string(),
+        "Runs if approved".to_string(),
+        actions.clone(),
+    );
+    assert!(matches!(result, Err(ContractError::Unauthorized)));
+    let noop = Box::new(|_: &[u8]| Ok(Box::new(|| ()) as CallbackEffect));
+    let result = contract.register_callback("noop".to_string(), noop, "mallory".to_string());
+    assert!(matches!(result, Err(ContractError::Unauthorized)));
+
+    assert!(matches!(create_proposal(&mut contract, Vec::new()), Err(ContractError::InvalidSettings)));
+    let result = create_proposal(&mut contract, vec![AdminAction::Callback { name: "missing".to_string(), payload: Vec::new() }]);
+    assert!(matches!(result, Err(ContractError::CallbackNotFound)));
+
+    let result = contract.set_config(ConfigParam::ProposalDuration(60), "owner".to_string());
+    assert!(matches!(result, Err(ContractError::InvalidConfig)));
+    let result = contract.set_config(ConfigParam::ProposalQuorum(0), "owner".to_string());
+    assert!(matches!(result, Err(ContractError::InvalidConfig)));
+
+    let poll_id = create_proposal(&mut contract, actions).unwrap();
+    let result = contract.vote(poll_id, "outsider".to_string(), 0);
+    assert!(matches!(result, Err(ContractError::NotEligible)));
+    let result = contract.close_poll(poll_id, "owner".to_string());
+    assert!(matches!(result, Err(ContractError::PollNotEnded)));
+    contract.grant_role(Role::Moderator, "mod".to_string(), "owner".to_string()).unwrap();
+    let result = contract.cancel_poll(poll_id, "mod".to_string(), "Veto".to_string());
+    assert!(matches!(result, Err(ContractError::PollNotEnded)));
+    assert!(matches!(contract.execute_proposal(poll_id), Err(ContractError::PollNotFinalized)));
+    assert!(matches!(contract.execute_proposal(99), Err(ContractError::ProposalNotFound)));
+}
+
+// Once a council exists, proposals and callbacks need its approval
+#[test]
+fn test_council_gates_proposals() {
+    let mut contract = proposal_contract();
+    let members = vec!["alice".to_string(), "bob".to_string(), "carol".to_string()];
+    contract.set_council(members, 2, "owner".to_string()).unwrap();
+
+    let result = create_proposal(&mut contract, vec![AdminAction::GrantRole { role: Role::Admin, account: "owner2".to_string() }]);
+    assert!(matches!(result, Err(ContractError::CouncilApprovalRequired)));
+
+    // A callback registered now is staged until the council enables it
+    let calls = Rc::new(RefCell::new(0));
+    let counter = Rc::clone(&calls);
+    contract.register_callback(
+        "treasury".to_string(),
+        Box::new(move |_: &[u8]| {
+            let counter = Rc::clone(&counter);
+            Ok(Box::new(move || *counter.borrow_mut() += 1) as CallbackEffect)
+        }),
+        "owner".to_string(),
+    ).unwrap();
+    let propose = AdminAction::CreateProposal {
+        title: "Fund the treasury".to_string(),
+        description: "Runs if approved".to_string(),
+        actions: vec![AdminAction::Callback { name: "treasury".to_string(), payload: Vec::new() }],
+    };
+    let proposal_id = contract.propose_admin_action(propose.clone(), "alice".to_string()).unwrap();
+    let result = contract.approve_admin_action(proposal_id, "bob".to_string());
+    assert!(matches!(result, Err(ContractError::CallbackNotFound)));
+
+    let enable = AdminAction::EnableCallback { name: "treasury".to_string() };
+    let proposal_id = contract.propose_admin_action(enable.clone(), "alice".to_string()).unwrap();
+    contract.approve_admin_action(proposal_id, "bob".to_string()).unwrap();
+    let proposal_id = contract.propose_admin_action(enable, "alice".to_string()).unwrap();
+    let result = contract.approve_admin_action(proposal_id, "bob".to_string());
+    assert!(matches!(result, Err(ContractError::CallbackNotFound)));
+
+    let proposal_id = contract.propose_admin_action(propose, "alice".to_string()).unwrap();
+    contract.approve_admin_action(proposal_id, "carol".to_string()).unwrap();
+    let poll_id = 0;
+    assert_eq!(contract.get_poll(poll_id).unwrap().creator, "alice");
+
+    // Not even the council can cut the vote short
+    let cancel = AdminAction::CancelPoll { poll_id, reason: "Veto".to_string() };
+    for action in [AdminAction::ClosePoll { poll_id }, cancel] {
+        let proposal_id = contract.propose_admin_action(action, "alice".to_string()).unwrap();
+        let result = contract.approve_admin_action(proposal_id, "bob".to_string());
+        assert!(matches!(result, Err(ContractError::PollNotEnded)));
+    }
+
+    decide(&mut contract, poll_id, 3, 0);
+    assert_eq!(*calls.borrow(), 1);
+    assert!(contract.get_executable_proposal(poll_id).unwrap().executed_at.is_some());
+}
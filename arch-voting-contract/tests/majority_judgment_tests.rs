@@ -0,0 +1,113 @@
+// Tests for majority judgment grading polls
+use arch_voting_contract::{
+    ContractError, PollMethod, PollOutcome, PollSettings, RunoffConfig, VoteChoice, VotingContract,
+};
+
+mod common;
+use common::{open_method_poll, open_poll, setup_contract};
+
+fn create_poll(contract: &mut VotingContract, grades: Vec<String>, options: Vec<&str>) -> Result<u64, ContractError> {
+    open_method_poll(contract, options, PollMethod::MajorityJudgment { grades })
+}
+
+fn scale() -> Vec<String> {
+    ["Reject", "Poor", "Acceptable", "Good", "Excellent"].iter().map(|grade| grade.to_string()).collect()
+}
+
+// Options rank by median grade, and equal medians are split by removing
+// median grades until they differ
+#[test]
+fn test_majority_judgment_ranking() {
+    let mut contract = setup_contract();
+    let poll_id = create_poll(&mut contract, scale(), vec!["Proposal A", "Proposal B", "Proposal C"]).unwrap();
+
+    let ballots = [[4, 2, 0], [3, 2, 0], [2, 2, 4], [2, 2, 4], [1, 2, 4]];
+    for (voter, grades) in ballots.iter().enumerate() {
+        contract.vote_grades(poll_id, format!("voter{}", voter), grades.to_vec()).unwrap();
+    }
+
+    let results = contract.get_majority_judgment_results(poll_id).unwrap();
+    assert_eq!(results.scale, scale());
+    assert_eq!(results.distributions, vec![vec![0, 1, 2, 1, 1], vec![0, 0, 5, 0, 0], vec![2, 0, 0, 0, 3]]);
+    assert_eq!(results.medians, vec![Some(2), Some(2), Some(4)]);
+    assert_eq!(results.above_median, vec![2, 0, 0]);
+    assert_eq!(results.below_median, vec![1, 0, 2]);
+    assert_eq!(results.ranking, vec![2, 0, 1]);
+    assert_eq!(results.winners, vec![2]);
+
+    contract.close_poll(poll_id, "creator".to_string()).unwrap();
+    assert_eq!(contract.get_winner(poll_id).unwrap(), PollOutcome::Winner { option_index: 2, tie_broken: false });
+
+    let proof = contract.prove_vote(poll_id, "voter0").unwrap();
+    assert_eq!(proof.choice, VoteChoice::Grades(vec![4, 2, 0]));
+    assert!(proof.verify(&contract.get_state_root()));
+}
+
+// Identical grade distributions cannot be separated, so the tie-break
+// policy decides
+#[test]
+fn test_majority_judgment_tie() {
+    let mut contract = setup_contract();
+    let poll_id = create_poll(&mut contract, scale(), vec!["Proposal A", "Proposal B"]).unwrap();
+
+    let results = contract.get_majority_judgment_results(poll_id).unwrap();
+    assert_eq!(results.medians, vec![None, None]);
+
+    contract.vote_grades(poll_id, "alice".to_string(), vec![3, 1]).unwrap();
+    contract.vote_grades(poll_id, "bob".to_string(), vec![1, 3]).unwrap();
+
+    let results = contract.get_majority_judgment_results(poll_id).unwrap();
+    assert_eq!(results.winners, vec![0, 1]);
+
+    contract.close_poll(poll_id, "creator".to_string()).unwrap();
+    assert_eq!(contract.get_winner(poll_id).unwrap(), PollOutcome::Winner { option_index: 0, tie_broken: true });
+}
+
+// Scales need two distinct grades, and ballots must grade every option
+#[test]
+fn test_majority_judgment_validation() {
+    let mut contract = setup_contract();
+
+    let result = create_poll(&mut contract, vec!["Pass".to_string()], vec!["A", "B"]);
+    assert!(matches!(result, Err(ContractError::InvalidSettings)));
+    let result = create_poll(&mut contract, vec!["Good".to_string(), " good".to_string()], vec!["A", "B"]);
+    assert!(matches!(result, Err(ContractError::InvalidSettings)));
+
+    let result = open_poll(
+        &mut contract,
+        vec!["A", "B"],
+        PollSettings {
+            method: PollMethod::MajorityJudgment { grades: scale() },
+            runoff: Some(RunoffConfig { majority_bps: 5000, top_n: 2, duration: 100 }),
+            ..PollSettings::default()
+        },
+    );
+    assert!(matches!(result, Err(ContractError::InvalidSettings)));
+
+    let poll_id = create_poll(&mut contract, scale(), vec!["A", "B"]).unwrap();
+    let result = contract.vote_grades(poll_id, "alice".to_string(), vec![4]);
+    assert!(matches!(result, Err(ContractError::InvalidOption)));
+    let result = contract.vote_grades(poll_id, "alice".to_string(), vec![5, 0]);
+    assert!(matches!(result, Err(ContractError::ScoreOutOfRange)));
+    let result = contract.vote(poll_id, "alice".to_string(), 0);
+    assert!(matches!(result, Err(ContractError::MethodMismatch)));
+
+    // Grade sums are not vote shares
+    assert!(matches!(contract.get_ordered_results(poll_id), Err(ContractError::MethodMismatch)));
+    assert!(matches!(contract.get_detailed_results(poll_id), Err(ContractError::MethodMismatch)));
+
+    let plurality = contract.create_poll(
+        "creator".to_string(),
+        "Plurality".to_string(),
+        "One choice".to_string(),
+        vec!["Yes", "No"],
+        0,
+        u64::MAX,
+    ).unwrap();
+    let result = contract.get_majority_judgment_results(plurality);
+    assert!(matches!(result, Err(ContractError::MethodMismatch)));
+
+    contract.cancel_poll(poll_id, "creator".to_string(), "Duplicate poll".to_string()).unwrap();
+    let result = contract.get_majority_judgment_results(poll_id);
+    assert!(matches!(result, Err(ContractError::PollCancelled)));
+}
@@ -0,0 +1,139 @@
+// Tests for the Merkle commitments over votes and contract state
+use arch_voting_contract::merkle::{self, MerkleTree, EMPTY_ROOT};
+use arch_voting_contract::{
+    ContractError, PollSettings, Role, RunoffConfig, TieBreakPolicy, VoteChoice, VotingContract,
+};
+
+mod common;
+use common::setup_contract;
+
+fn setup_poll(contract: &mut VotingContract) -> u64 {
+    contract.grant_role(Role::PollCreator, "creator".to_string(), "owner".to_string()).unwrap();
+    contract.create_poll(
+        "creator".to_string(),
+        "Merkle Poll".to_string(),
+        "Poll used for commitment tests".to_string(),
+        vec!["Yes".to_string(), "No".to_string(), "Abstain".to_string()],
+        0,
+        u64::MAX,
+    ).unwrap()
+}
+
+// Every leaf of trees with odd and even sizes should prove against the root
+#[test]
+fn test_tree_proofs_for_all_sizes() {
+    for size in 1..=9u32 {
+        let leaves: Vec<_> = (0..size).map(|i| merkle::vote_leaf("wallet", &VoteChoice::Single(i))).collect();
+        let tree = MerkleTree::new(leaves.clone());
+
+        for (index, leaf) in leaves.iter().enumerate() {
+            let proof = tree.proof(index).unwrap();
+            assert!(proof.verify(&tree.root(), *leaf));
+        }
+        assert!(tree.proof(size as usize).is_none());
+    }
+
+    assert_eq!(MerkleTree::new(Vec::new()).root(), EMPTY_ROOT);
+}
+
+// Roots should change with every vote and not depend on insertion order
+#[test]
+fn test_vote_root_tracks_votes() {
+    let mut contract_a = VotingContract::new("owner".to_string());
+    let mut contract_b = VotingContract::new("owner".to_string());
+    let poll_a = setup_poll(&mut contract_a);
+    let poll_b = setup_poll(&mut contract_b);
+
+    assert_eq!(contract_a.get_vote_root(poll_a).unwrap(), EMPTY_ROOT);
+
+    contract_a.vote(poll_a, "alice".to_string(), 0).unwrap();
+    let after_one = contract_a.get_vote_root(poll_a).unwrap();
+    assert_ne!(after_one, EMPTY_ROOT);
+
+    contract_a.vote(poll_a, "bob".to_string(), 1).unwrap();
+    assert_ne!(contract_a.get_vote_root(poll_a).unwrap(), after_one);
+
+    // Same votes in the opposite order
+    contract_b.vote(poll_b, "bob".to_string(), 1).unwrap();
+    contract_b.vote(poll_b, "alice".to_string(), 0).unwrap();
+
+    assert_eq!(contract_a.get_vote_root(poll_a).unwrap(), contract_b.get_vote_root(poll_b).unwrap());
+    assert_eq!(contract_a.get_state_root(), contract_b.get_state_root());
+}
+
+// A voter can verify their ballot against the published state root
+#[test]
+fn test_vote_inclusion_proof() {
+    let mut contract = VotingContract::new("owner".to_string());
+    let first_poll = setup_poll(&mut contract);
+    let poll_id = setup_poll(&mut contract);
+
+    contract.vote(first_poll, "carol".to_string(), 2).unwrap();
+    for (i, wallet) in ["alice", "bob", "carol", "dave", "erin"].iter().enumerate() {
+        contract.vote(poll_id, wallet.to_string(), (i % 3) as u32).unwrap();
+    }
+
+    let state_root = contract.get_state_root();
+    let proof = contract.prove_vote(poll_id, "dave").unwrap();
+    assert_eq!(proof.choice, VoteChoice::Single(0));
+    assert_eq!(proof.vote_root, contract.get_vote_root(poll_id).unwrap());
+    assert!(proof.verify(&state_root));
+
+    // Claiming a different option must fail
+    let mut forged = proof.clone();
+    forged.choice = VoteChoice::Single(1);
+    assert!(!forged.verify(&state_root));
+
+    // Any later change to the state invalidates old roots
+    contract.vote(poll_id, "frank".to_string(), 1).unwrap();
+    assert!(!proof.verify(&contract.get_state_root()));
+    assert!(contract.prove_vote(poll_id, "dave").unwrap().verify(&contract.get_state_root()));
+
+    // Wallets without a vote get no proof
+    let result = contract.prove_vote(poll_id, "mallory");
+    assert!(matches!(result, Err(ContractError::VoteNotFound)));
+    let result = contract.prove_vote(99, "dave");
+    assert!(matches!(result, Err(ContractError::PollNotFound)));
+}
+
+// The header commits to the poll's settings and cancellation, not just its text
+#[test]
+fn test_header_covers_settings() {
+    let settings = [
+        PollSettings::default(),
+        PollSettings { tie_break: TieBreakPolicy::SeededDraw { seed: [1; 32] }, ..PollSettings::default() },
+        PollSettings { tie_break: TieBreakPolicy::SeededDraw { seed: [2; 32] }, ..PollSettings::default() },
+        PollSettings {
+            runoff: Some(RunoffConfig { majority_bps: 5000, top_n: 2, duration: 60 }),
+            ..PollSettings::default()
+        },
+        PollSettings {
+            eligible_voters: Some(["alice".to_string()].into_iter().collect()),
+            ..PollSettings::default()
+        },
+    ];
+
+    let mut headers = Vec::new();
+    for settings in settings {
+        let mut contract = setup_contract();
+        let poll_id = contract.create_poll_with_settings(
+            "creator".to_string(),
+            "Merkle Poll".to_string(),
+            "Poll used for commitment tests".to_string(),
+            vec!["Yes", "No", "Abstain"],
+            0,
+            u64::MAX,
+            settings,
+        ).unwrap();
+        headers.push(merkle::poll_header_hash(contract.get_poll(poll_id).unwrap()));
+    }
+    for (i, header) in headers.iter().enumerate() {
+        assert!(headers[i + 1..].iter().all(|other| other != header));
+    }
+
+    let mut contract = VotingContract::new("owner".to_string());
+    let poll_id = setup_poll(&mut contract);
+    let before = merkle::poll_header_hash(contract.get_poll(poll_id).unwrap());
+    contract.cancel_poll(poll_id, "creator".to_string(), "Fraud".to_string()).unwrap();
+    assert_ne!(merkle::poll_header_hash(contract.get_poll(poll_id).unwrap()), before);
+}
@@ -0,0 +1,94 @@
+// Tests for structured poll options and their metadata
+use arch_voting_contract::merkle;
+use arch_voting_contract::models::{MAX_LABEL_LEN, MAX_OPTION_DESCRIPTION_LEN, MAX_URI_LEN};
+use arch_voting_contract::{ContractError, PollOption, PollSettings, VotingContract};
+
+mod common;
+use common::{open_poll, setup_contract};
+
+fn create_poll(contract: &mut VotingContract, options: Vec<PollOption>) -> Result<u64, ContractError> {
+    open_poll(contract, options, PollSettings::default())
+}
+
+fn grant(label: &str, proposer: &str) -> PollOption {
+    PollOption {
+        label: label.to_string(),
+        description: format!("Funding request for {}", label),
+        uri: Some(format!("https://grants.example/{}", label.to_lowercase())),
+        proposer: Some(proposer.to_string()),
+        image_hash: Some(merkle::hash_bytes(label.as_bytes())),
+    }
+}
+
+// Metadata is stored with the poll and returned alongside the counts
+#[test]
+fn test_results_include_metadata() {
+    let mut contract = setup_contract();
+    let options = vec![grant("Indexer", "bc1qalice"), grant("Wallet", "bc1qbob")];
+    let poll_id = create_poll(&mut contract, options.clone()).unwrap();
+
+    assert_eq!(contract.get_poll(poll_id).unwrap().options, options);
+
+    contract.vote(poll_id, "w1".to_string(), 1).unwrap();
+    let results = contract.get_ordered_results(poll_id).unwrap();
+    assert_eq!(results.options[0].option, options[0]);
+    assert_eq!(results.options[1].option, options[1]);
+    assert_eq!(results.options[1].count, 1);
+
+    // Plain labels still work and carry no metadata
+    let poll_id = contract.create_poll(
+        "creator".to_string(),
+        "Simple".to_string(),
+        "Labels only".to_string(),
+        vec!["Yes", "No"],
+        0,
+        u64::MAX,
+    ).unwrap();
+    let poll = contract.get_poll(poll_id).unwrap();
+    assert_eq!(poll.option_labels(), vec!["Yes", "No"]);
+    assert_eq!(poll.options[0], PollOption::new("Yes"));
+}
+
+// Oversized or empty fields are rejected when the poll is created
+#[test]
+fn test_option_size_limits() {
+    let mut contract = setup_contract();
+
+    let mut at_limit = grant("Indexer", "bc1qalice");
+    at_limit.description = "d".repeat(MAX_OPTION_DESCRIPTION_LEN);
+    assert!(create_poll(&mut contract, vec![at_limit, grant("Wallet", "bc1qbob")]).is_ok());
+
+    let mut long_label = grant("Indexer", "bc1qalice");
+    long_label.label = "l".repeat(MAX_LABEL_LEN + 1);
+    let mut long_description = grant("Indexer", "bc1qalice");
+    long_description.description = "d".repeat(MAX_OPTION_DESCRIPTION_LEN + 1);
+    let mut long_uri = grant("Indexer", "bc1qalice");
+    long_uri.uri = Some("u".repeat(MAX_URI_LEN + 1));
+    let mut empty_label = grant("Indexer", "bc1qalice");
+    empty_label.label = "  ".to_string();
+
+    for option in [long_label, long_description, long_uri] {
+        let result = create_poll(&mut contract, vec![option, grant("Wallet", "bc1qbob")]);
+        assert!(matches!(result, Err(ContractError::OptionTooLarge)));
+    }
+    let result = create_poll(&mut contract, vec![empty_label, grant("Wallet", "bc1qbob")]);
+    assert!(matches!(result, Err(ContractError::EmptyLabel)));
+}
+
+// Metadata is part of the committed poll header
+#[test]
+fn test_metadata_is_committed() {
+    let mut contract_a = setup_contract();
+    let mut contract_b = setup_contract();
+
+    let mut altered = grant("Indexer", "bc1qalice");
+    altered.image_hash = None;
+    let poll_a = create_poll(&mut contract_a, vec![grant("Indexer", "bc1qalice"), grant("Wallet", "bc1qbob")]).unwrap();
+    let poll_b = create_poll(&mut contract_b, vec![altered, grant("Wallet", "bc1qbob")]).unwrap();
+
+    assert_ne!(
+        merkle::poll_header_hash(contract_a.get_poll(poll_a).unwrap()),
+        merkle::poll_header_hash(contract_b.get_poll(poll_b).unwrap())
+    );
+    assert_ne!(contract_a.get_state_root(), contract_b.get_state_root());
+}
@@ -0,0 +1,66 @@
+// Tests for two-step contract ownership transfer
+use arch_voting_contract::{ContractError, Event, Role, VotingContract};
+
+// Ownership only moves once the nominee accepts
+#[test]
+fn test_ownership_transfer() {
+    let mut contract = VotingContract::new("old_owner".to_string());
+
+    let result = contract.propose_owner("mallory".to_string(), "mallory".to_string());
+    assert!(matches!(result, Err(ContractError::Unauthorized)));
+
+    contract.propose_owner("new_owner".to_string(), "old_owner".to_string()).unwrap();
+    assert_eq!(contract.get_owner(), "old_owner");
+    assert_eq!(contract.get_pending_owner(), Some("new_owner"));
+
+    // Only the nominee can accept
+    let result = contract.accept_ownership("mallory".to_string());
+    assert!(matches!(result, Err(ContractError::Unauthorized)));
+
+    contract.accept_ownership("new_owner".to_string()).unwrap();
+    assert_eq!(contract.get_owner(), "new_owner");
+    assert_eq!(contract.get_pending_owner(), None);
+
+    // Admin rights follow ownership
+    assert!(contract.has_role(Role::Admin, "new_owner"));
+    assert!(!contract.has_role(Role::Admin, "old_owner"));
+    let result = contract.grant_role(Role::PollCreator, "x".to_string(), "old_owner".to_string());
+    assert!(matches!(result, Err(ContractError::Unauthorized)));
+
+    assert_eq!(contract.get_events(), &[
+        Event::OwnershipTransferProposed {
+            owner: "old_owner".to_string(),
+            proposed_owner: "new_owner".to_string(),
+        },
+        Event::OwnershipTransferred {
+            previous_owner: "old_owner".to_string(),
+            new_owner: "new_owner".to_string(),
+        },
+    ]);
+}
+
+// A pending nomination can be withdrawn before it is accepted
+#[test]
+fn test_cancel_ownership_transfer() {
+    let mut contract = VotingContract::new("owner".to_string());
+
+    let result = contract.cancel_ownership_transfer("owner".to_string());
+    assert!(matches!(result, Err(ContractError::NoPendingTransfer)));
+
+    contract.propose_owner("nominee".to_string(), "owner".to_string()).unwrap();
+
+    let result = contract.cancel_ownership_transfer("nominee".to_string());
+    assert!(matches!(result, Err(ContractError::Unauthorized)));
+
+    contract.cancel_ownership_transfer("owner".to_string()).unwrap();
+    assert_eq!(contract.get_pending_owner(), None);
+
+    let result = contract.accept_ownership("nominee".to_string());
+    assert!(matches!(result, Err(ContractError::NoPendingTransfer)));
+    assert_eq!(contract.get_owner(), "owner");
+
+    assert_eq!(contract.get_events().last(), Some(&Event::OwnershipTransferCancelled {
+        owner: "owner".to_string(),
+        proposed_owner: "nominee".to_string(),
+    }));
+}
@@ -0,0 +1,126 @@
+// Tests for editing polls before voting starts
+use arch_voting_contract::{ContractError, Event, PollOption, PollUpdate, Role, VotingContract};
+
+mod common;
+use common::setup_contract;
+
+// Far enough in the future that voting never starts during the test
+const FUTURE: u64 = u64::MAX - 1000;
+
+fn create_poll(contract: &mut VotingContract, start_time: u64) -> u64 {
+    contract.create_poll(
+        "creator".to_string(),
+        "Budgte Vote".to_string(),
+        "Pick a budget".to_string(),
+        vec!["Low", "Hihg"],
+        start_time,
+        u64::MAX,
+    ).unwrap()
+}
+
+// Edits replace the fields and record the old values
+#[test]
+fn test_update_records_history() {
+    let mut contract = setup_contract();
+    let poll_id = create_poll(&mut contract, FUTURE);
+
+    let update = PollUpdate {
+        title: Some("Budget Vote".to_string()),
+        options: Some(vec![PollOption::new("Low"), PollOption::new("High"), PollOption::new("None")]),
+        ..PollUpdate::default()
+    };
+    contract.update_poll(poll_id, update, "creator".to_string()).unwrap();
+
+    let update = PollUpdate {
+        start_time: Some(FUTURE + 10),
+        ..PollUpdate::default()
+    };
+    contract.update_poll(poll_id, update, "owner".to_string()).unwrap();
+
+    let poll = contract.get_poll(poll_id).unwrap();
+    assert_eq!(poll.title, "Budget Vote");
+    assert_eq!(poll.description, "Pick a budget");
+    assert_eq!(poll.option_labels(), vec!["Low", "High", "None"]);
+    assert_eq!(poll.start_time, FUTURE + 10);
+    assert_eq!(contract.get_results(poll_id).unwrap().counts.len(), 3);
+
+    assert_eq!(poll.edit_history.len(), 2);
+    let first = &poll.edit_history[0];
+    assert_eq!(first.editor, "creator");
+    assert_eq!(first.previous.title.as_deref(), Some("Budgte Vote"));
+    assert_eq!(first.previous.description, None);
+    assert_eq!(first.previous.options.as_ref().unwrap().len(), 2);
+    assert_eq!(poll.edit_history[1].editor, "owner");
+    assert_eq!(poll.edit_history[1].previous.start_time, Some(FUTURE));
+
+    assert!(contract.get_events().contains(&Event::PollUpdated {
+        poll_id,
+        editor: "creator".to_string(),
+    }));
+
+    // An empty update changes nothing and is not recorded
+    contract.update_poll(poll_id, PollUpdate::default(), "creator".to_string()).unwrap();
+    assert_eq!(contract.get_poll(poll_id).unwrap().edit_history.len(), 2);
+}
+
+// Only the creator or an admin may edit, and the new values must be valid
+#[test]
+fn test_update_validation() {
+    let mut contract = setup_contract();
+    let poll_id = create_poll(&mut contract, FUTURE);
+
+    let retitle = PollUpdate {
+        title: Some("Hijacked".to_string()),
+        ..PollUpdate::default()
+    };
+    let result = contract.update_poll(poll_id, retitle.clone(), "mallory".to_string());
+    assert!(matches!(result, Err(ContractError::Unauthorized)));
+
+    let bad_times = PollUpdate {
+        end_time: Some(FUTURE),
+        ..PollUpdate::default()
+    };
+    let result = contract.update_poll(poll_id, bad_times, "creator".to_string());
+    assert!(matches!(result, Err(ContractError::InvalidTimeRange)));
+
+    let duplicates = PollUpdate {
+        options: Some(vec![PollOption::new("Yes"), PollOption::new(" yes")]),
+        ..PollUpdate::default()
+    };
+    let result = contract.update_poll(poll_id, duplicates, "creator".to_string());
+    assert!(matches!(result, Err(ContractError::DuplicateOption)));
+
+    let result = contract.update_poll(99, PollUpdate::default(), "creator".to_string());
+    assert!(matches!(result, Err(ContractError::PollNotFound)));
+
+    // Failed edits leave the poll untouched
+    let poll = contract.get_poll(poll_id).unwrap();
+    assert_eq!(poll.title, "Budgte Vote");
+    assert!(poll.edit_history.is_empty());
+
+    // Admins may edit anyone's poll
+    contract.grant_role(Role::Admin, "admin".to_string(), "owner".to_string()).unwrap();
+    contract.update_poll(poll_id, retitle, "admin".to_string()).unwrap();
+    assert_eq!(contract.get_poll(poll_id).unwrap().title, "Hijacked");
+}
+
+// Polls can no longer be edited once voting has started
+#[test]
+fn test_no_edits_after_start() {
+    let mut contract = setup_contract();
+    let poll_id = create_poll(&mut contract, 0);
+    contract.vote(poll_id, "alice".to_string(), 0).unwrap();
+
+    let update = PollUpdate {
+        title: Some("Budget Vote".to_string()),
+        ..PollUpdate::default()
+    };
+    let result = contract.update_poll(poll_id, update, "creator".to_string());
+    assert!(matches!(result, Err(ContractError::PollAlreadyStarted)));
+
+    // Closed polls cannot be edited either
+    let upcoming = create_poll(&mut contract, FUTURE);
+    contract.close_poll(upcoming, "creator".to_string()).unwrap();
+    let result = contract.update_poll(upcoming, PollUpdate::default(), "creator".to_string());
+    assert!(matches!(result, Err(ContractError::PollNotActive)));
+}
@@ -0,0 +1,102 @@
+// Tests for positional scoring rules over ranked ballots
+use arch_voting_contract::positional::POINT_SCALE;
+use arch_voting_contract::{
+    CondorcetRule, ContractError, PollMethod, PollOutcome, PollSettings, PositionalRule, RunoffConfig,
+    VotingContract,
+};
+
+mod common;
+use common::{cast, open_method_poll, open_poll, setup_contract};
+
+fn create_poll(contract: &mut VotingContract, method: PollMethod) -> Result<u64, ContractError> {
+    open_method_poll(contract, vec!["A", "B", "C", "D"], method)
+}
+
+// Three full, two full and two partial ballots
+fn cast_ballots(contract: &mut VotingContract, poll_id: u64) {
+    let ballots = [
+        (3, vec![0, 1, 2, 3]),
+        (2, vec![1, 2, 3, 0]),
+        (2, vec![2, 1]),
+    ];
+
+    let mut voter = 0;
+    for (count, ranking) in ballots {
+        cast(contract, poll_id, count, &ranking, &mut voter);
+    }
+}
+
+// The same ballots can be compared under every rule
+#[test]
+fn test_compare_rules() {
+    let mut contract = setup_contract();
+    let poll_id = create_poll(&mut contract, PollMethod::Positional { rule: PositionalRule::Borda }).unwrap();
+    cast_ballots(&mut contract, poll_id);
+
+    let borda = contract.get_positional_results(poll_id, PositionalRule::Borda).unwrap();
+    let whole: Vec<u128> = borda.points.iter().map(|points| points / POINT_SCALE).collect();
+    assert_eq!(whole, vec![9, 16, 13, 2]);
+    assert_eq!(borda.ranking, vec![1, 2, 0, 3]);
+    assert_eq!(borda.counts, vec![3, 2, 2, 0]);
+
+    let dowdall = contract.get_positional_results(poll_id, PositionalRule::Dowdall).unwrap();
+    assert_eq!(dowdall.points, vec![3_500_000, 4_500_000, 3_999_999, 1_416_666]);
+    assert_eq!(dowdall.ranking, vec![1, 2, 0, 3]);
+
+    // One point for first place only is plain plurality
+    let first_only = contract.get_positional_results(poll_id, PositionalRule::Custom(vec![1])).unwrap();
+    assert_eq!(first_only.points, vec![3 * POINT_SCALE, 2 * POINT_SCALE, 2 * POINT_SCALE, 0]);
+    assert_eq!(first_only.ranking, vec![0, 1, 2, 3]);
+
+    // Condorcet tallies are available on the same ballots
+    let condorcet = contract.get_condorcet_results(poll_id).unwrap();
+    assert_eq!(condorcet.condorcet_winner, Some(1));
+
+    contract.close_poll(poll_id, "creator".to_string()).unwrap();
+    assert_eq!(contract.get_winner(poll_id).unwrap(), PollOutcome::Winner { option_index: 1, tie_broken: false });
+}
+
+// Condorcet polls can be re-counted with positional rules too
+#[test]
+fn test_positional_on_condorcet_poll() {
+    let mut contract = setup_contract();
+    let poll_id = create_poll(&mut contract, PollMethod::Ranked { rule: CondorcetRule::Schulze }).unwrap();
+    cast_ballots(&mut contract, poll_id);
+
+    let custom = contract.get_positional_results(poll_id, PositionalRule::Custom(vec![5, 3, 1])).unwrap();
+    let whole: Vec<u128> = custom.points.iter().map(|points| points / POINT_SCALE).collect();
+    assert_eq!(whole, vec![15, 25, 19, 2]);
+}
+
+// Weights must award something, and the poll must take rankings
+#[test]
+fn test_positional_validation() {
+    let mut contract = setup_contract();
+
+    let result = create_poll(&mut contract, PollMethod::Positional { rule: PositionalRule::Custom(vec![0, 0]) });
+    assert!(matches!(result, Err(ContractError::InvalidSettings)));
+    let result = open_poll(
+        &mut contract,
+        vec!["A", "B", "C"],
+        PollSettings {
+            method: PollMethod::Positional { rule: PositionalRule::Borda },
+            runoff: Some(RunoffConfig { majority_bps: 5000, top_n: 2, duration: 100 }),
+            ..PollSettings::default()
+        },
+    );
+    assert!(matches!(result, Err(ContractError::InvalidSettings)));
+
+    let poll_id = create_poll(&mut contract, PollMethod::Positional { rule: PositionalRule::Dowdall }).unwrap();
+    let result = contract.get_positional_results(poll_id, PositionalRule::Custom(Vec::new()));
+    assert!(matches!(result, Err(ContractError::InvalidSettings)));
+    let result = contract.vote(poll_id, "alice".to_string(), 0);
+    assert!(matches!(result, Err(ContractError::MethodMismatch)));
+
+    let plurality = create_poll(&mut contract, PollMethod::Plurality).unwrap();
+    let result = contract.get_positional_results(plurality, PositionalRule::Borda);
+    assert!(matches!(result, Err(ContractError::MethodMismatch)));
+
+    contract.cancel_poll(poll_id, "creator".to_string(), "Duplicate poll".to_string()).unwrap();
+    let result = contract.get_positional_results(poll_id, PositionalRule::Borda);
+    assert!(matches!(result, Err(ContractError::PollCancelled)));
+}
@@ -0,0 +1,102 @@
+// Tests for paginated and filtered poll queries
+use arch_voting_contract::{
+    PollQuery, PollSortKey, PollStatus, Role, VotingContract,
+};
+
+mod common;
+use common::setup_contract;
+
+fn query_contract() -> VotingContract {
+    let mut contract = setup_contract();
+    for creator in ["alice", "bob"] {
+        contract.grant_role(Role::PollCreator, creator.to_string(), "owner".to_string()).unwrap();
+    }
+
+    // Ten open polls alternating between two creators
+    for i in 0..10u64 {
+        let creator = ["alice", "bob"][i as usize % 2];
+        contract.create_poll(
+            creator.to_string(),
+            format!("Poll {} about {}", i, if i < 5 { "Budget" } else { "Roadmap" }),
+            "Query test poll".to_string(),
+            vec!["Yes".to_string(), "No".to_string()],
+            i,
+            u64::MAX - i,
+        ).unwrap();
+    }
+
+    contract
+}
+
+// Walking every page returns each poll exactly once, in order
+#[test]
+fn test_cursor_pagination() {
+    let contract = query_contract();
+
+    let mut query = PollQuery { limit: 3, ..PollQuery::default() };
+    let mut seen = Vec::new();
+    loop {
+        let page = contract.query_polls(&query);
+        assert!(page.poll_ids.len() <= 3);
+        seen.extend(page.poll_ids);
+        match page.next_cursor {
+            Some(cursor) => query.after = Some(cursor),
+            None => break,
+        }
+    }
+    assert_eq!(seen, (0..10).collect::<Vec<u64>>());
+
+    // Same query twice gives the same page
+    let query = PollQuery { limit: 4, descending: true, ..PollQuery::default() };
+    assert_eq!(contract.query_polls(&query), contract.query_polls(&query));
+    assert_eq!(contract.query_polls(&query).poll_ids, vec![9, 8, 7, 6]);
+
+    // Active listing is ordered too
+    assert_eq!(contract.get_active_polls(), (0..10).collect::<Vec<u64>>());
+}
+
+// Filters combine and sorting follows the chosen key
+#[test]
+fn test_filters_and_sorting() {
+    let mut contract = query_contract();
+
+    let query = PollQuery {
+        creator: Some("alice".to_string()),
+        title_contains: Some("budget".to_string()),
+        ..PollQuery::default()
+    };
+    assert_eq!(contract.query_polls(&query).poll_ids, vec![0, 2, 4]);
+
+    contract.set_poll_tags(3, vec!["treasury".to_string()], "bob".to_string()).unwrap();
+    contract.set_poll_tags(7, vec!["treasury".to_string(), "q3".to_string()], "bob".to_string()).unwrap();
+    assert!(contract.set_poll_tags(2, vec!["x".to_string()], "bob".to_string()).is_err());
+    let query = PollQuery { tag: Some("treasury".to_string()), ..PollQuery::default() };
+    assert_eq!(contract.query_polls(&query).poll_ids, vec![3, 7]);
+
+    contract.close_poll(5, "bob".to_string()).unwrap();
+    let query = PollQuery { status: Some(PollStatus::Closed), ..PollQuery::default() };
+    assert_eq!(contract.query_polls(&query).poll_ids, vec![5]);
+
+    // Windows that only overlap the earliest starts
+    let query = PollQuery { window: Some((0, 2)), ..PollQuery::default() };
+    assert_eq!(contract.query_polls(&query).poll_ids, vec![0, 1, 2]);
+
+    let query = PollQuery { sort_by: PollSortKey::EndTime, limit: 2, ..PollQuery::default() };
+    assert_eq!(contract.query_polls(&query).poll_ids, vec![9, 8]);
+
+    // Most votes first, ties broken by lowest ID
+    contract.vote(6, "w1".to_string(), 0).unwrap();
+    contract.vote(6, "w2".to_string(), 0).unwrap();
+    contract.vote(1, "w1".to_string(), 1).unwrap();
+    let query = PollQuery {
+        sort_by: PollSortKey::VoteCount,
+        descending: true,
+        limit: 3,
+        ..PollQuery::default()
+    };
+    let page = contract.query_polls(&query);
+    assert_eq!(page.poll_ids, vec![6, 1, 0]);
+
+    let next = PollQuery { after: page.next_cursor, ..query };
+    assert_eq!(contract.query_polls(&next).poll_ids, vec![2, 3, 4]);
+}
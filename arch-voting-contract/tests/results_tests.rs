@@ -0,0 +1,111 @@
+// Tests for the ordered results API
+use arch_voting_contract::results::{shares_bps, BASIS_POINTS};
+use arch_voting_contract::{ContractError, PollSettings, RoundingMode, VotingContract};
+
+mod common;
+use common::{open_poll, setup_contract};
+
+fn create_poll(contract: &mut VotingContract, options: &[&str]) -> Result<u64, ContractError> {
+    open_poll(contract, options.to_vec(), PollSettings::default())
+}
+
+// Options come back in index order with a stable ranking
+#[test]
+fn test_ordered_results() {
+    let mut contract = setup_contract();
+    let poll_id = create_poll(&mut contract, &["Red", "Blue", "Green", "Yellow"]).unwrap();
+
+    let votes = [(1, "w1"), (1, "w2"), (3, "w3"), (3, "w4"), (0, "w5")];
+    for (option, wallet) in votes {
+        contract.vote(poll_id, wallet.to_string(), option).unwrap();
+    }
+
+    let results = contract.get_ordered_results(poll_id).unwrap();
+    assert_eq!(results.total_votes, 5);
+
+    let labels: Vec<&str> = results.options.iter().map(|o| o.option.label.as_str()).collect();
+    assert_eq!(labels, vec!["Red", "Blue", "Green", "Yellow"]);
+
+    let counts: Vec<u64> = results.options.iter().map(|o| o.count).collect();
+    assert_eq!(counts, vec![1, 2, 0, 2]);
+
+    // Blue and Yellow tie for first; ties keep index order
+    assert_eq!(results.ranking, vec![1, 3, 0, 2]);
+    let ranks: Vec<u32> = results.options.iter().map(|o| o.rank).collect();
+    assert_eq!(ranks, vec![3, 1, 4, 1]);
+
+    let shares: Vec<u32> = results.options.iter().map(|o| o.share_bps).collect();
+    assert_eq!(shares, vec![2000, 4000, 0, 4000]);
+    assert_eq!(results, contract.get_ordered_results(poll_id).unwrap());
+}
+
+// Labels that only differ by Unicode form, case or padding are duplicates
+#[test]
+fn test_duplicate_labels_rejected() {
+    let mut contract = setup_contract();
+
+    let duplicates: [&[&str]; 4] = [
+        &["Yes", "No", "Yes"],
+        &["Yes", "yes"],
+        &["Café", "Cafe\u{301}"],
+        &["Ｙｅｓ", " Yes "],
+    ];
+    for options in duplicates {
+        let result = create_poll(&mut contract, options);
+        assert!(matches!(result, Err(ContractError::DuplicateOption)));
+    }
+
+    create_poll(&mut contract, &["Café", "Cafe"]).unwrap();
+}
+
+// Largest-remainder shares always add up to exactly 100.00%
+#[test]
+fn test_shares_sum_to_one_hundred_percent() {
+    // Thirds: 3333 + 3333 + 3333 leaves one basis point for the lowest index
+    assert_eq!(shares_bps(&[1, 1, 1], 3, RoundingMode::LargestRemainder), vec![3334, 3333, 3333]);
+    assert_eq!(shares_bps(&[1, 1, 1], 3, RoundingMode::Floor), vec![3333, 3333, 3333]);
+
+    // The largest remainder wins the extra point, not the lowest index
+    assert_eq!(shares_bps(&[1, 2, 4], 7, RoundingMode::LargestRemainder), vec![1429, 2857, 5714]);
+
+    assert_eq!(shares_bps(&[0, 0], 0, RoundingMode::LargestRemainder), vec![0, 0]);
+
+    for total in 1..60u64 {
+        let counts: Vec<u64> = (0..7).map(|i| (total * (i + 3)) % 11).collect();
+        let sum: u64 = counts.iter().sum();
+        if sum == 0 {
+            continue;
+        }
+        let shares = shares_bps(&counts, sum as u128, RoundingMode::LargestRemainder);
+        assert_eq!(shares.iter().sum::<u32>(), BASIS_POINTS);
+
+        let floored = shares_bps(&counts, sum as u128, RoundingMode::Floor);
+        assert!(floored.iter().sum::<u32>() <= BASIS_POINTS);
+    }
+
+    // Huge tallies do not overflow
+    let shares = shares_bps(&[u64::MAX / 2, u64::MAX / 2], (u64::MAX - 1) as u128, RoundingMode::LargestRemainder);
+    assert_eq!(shares, vec![5000, 5000]);
+    let shares = shares_bps(&[u64::MAX, u64::MAX], 2 * u64::MAX as u128, RoundingMode::LargestRemainder);
+    assert_eq!(shares, vec![5000, 5000]);
+}
+
+// The ordered results honour the requested rounding mode
+#[test]
+fn test_ordered_results_rounding() {
+    let mut contract = setup_contract();
+    let poll_id = create_poll(&mut contract, &["A", "B", "C"]).unwrap();
+    for (i, wallet) in ["w1", "w2", "w3"].iter().enumerate() {
+        contract.vote(poll_id, wallet.to_string(), i as u32).unwrap();
+    }
+
+    let exact = contract.get_ordered_results(poll_id).unwrap();
+    assert_eq!(exact.options.iter().map(|o| o.share_bps).sum::<u32>(), BASIS_POINTS);
+
+    let floored = contract.get_ordered_results_rounded(poll_id, RoundingMode::Floor).unwrap();
+    assert_eq!(floored.options.iter().map(|o| o.share_bps).sum::<u32>(), 9999);
+
+    // The float view remains available
+    let detailed = contract.get_detailed_results(poll_id).unwrap();
+    assert!((detailed["A"].1 - 33.33).abs() < 0.01);
+}
@@ -0,0 +1,137 @@
+// Tests for automatic majority runoffs
+use std::collections::BTreeSet;
+
+use arch_voting_contract::{
+    ContractError, PollSettings, RunoffConfig, VotingContract,
+};
+
+mod common;
+use common::setup_contract;
+
+fn runoff_settings(majority_bps: u32, top_n: u32) -> PollSettings {
+    PollSettings {
+        runoff: Some(RunoffConfig { majority_bps, top_n, duration: 7200 }),
+        ..PollSettings::default()
+    }
+}
+
+// Create a four-option poll, cast the given votes and close it
+fn closed_poll(contract: &mut VotingContract, settings: PollSettings, votes: &[u32]) -> u64 {
+    let poll_id = contract.create_poll_with_settings(
+        "creator".to_string(),
+        "Council Election".to_string(),
+        "Needs an absolute majority".to_string(),
+        vec!["Ann".to_string(), "Ben".to_string(), "Cat".to_string(), "Dan".to_string()],
+        0,
+        u64::MAX,
+        settings,
+    ).unwrap();
+
+    for (i, option) in votes.iter().enumerate() {
+        contract.vote(poll_id, format!("wallet{}", i), *option).unwrap();
+    }
+    contract.close_poll(poll_id, "creator".to_string()).unwrap();
+
+    poll_id
+}
+
+// Without a majority, finalization opens a runoff between the top options
+#[test]
+fn test_runoff_without_majority() {
+    let mut contract = setup_contract();
+    let poll_id = closed_poll(&mut contract, runoff_settings(5001, 2), &[0, 0, 0, 1, 1, 2, 3]);
+
+    contract.finalize_poll(poll_id).unwrap();
+
+    let parent = contract.get_poll(poll_id).unwrap();
+    let runoff_id = parent.runoff_poll.unwrap();
+    let runoff = contract.get_poll(runoff_id).unwrap();
+
+    assert_eq!(runoff.option_labels(), vec!["Ann", "Ben"]);
+    assert_eq!(runoff.parent_poll, Some(poll_id));
+    assert_eq!(runoff.creator, "creator");
+    assert_eq!(runoff.end_time - runoff.start_time, 7200);
+
+    // The child does not chain further majority runoffs
+    assert_eq!(runoff.settings.runoff, None);
+}
+
+// A majority winner, or a field that cannot be narrowed, needs no runoff
+#[test]
+fn test_no_runoff_when_not_needed() {
+    let mut contract = setup_contract();
+
+    let poll_id = closed_poll(&mut contract, runoff_settings(5001, 2), &[0, 0, 0, 1, 2]);
+    contract.finalize_poll(poll_id).unwrap();
+    assert_eq!(contract.get_poll(poll_id).unwrap().runoff_poll, None);
+
+    let poll_id = closed_poll(&mut contract, runoff_settings(5001, 4), &[0, 1, 2, 3]);
+    contract.finalize_poll(poll_id).unwrap();
+    assert_eq!(contract.get_poll(poll_id).unwrap().runoff_poll, None);
+
+    // Options tied for first place fill the runoff
+    let poll_id = closed_poll(&mut contract, runoff_settings(5001, 2), &[0, 0, 1, 2, 3, 3]);
+    contract.finalize_poll(poll_id).unwrap();
+    let runoff_id = contract.get_poll(poll_id).unwrap().runoff_poll.unwrap();
+    assert_eq!(contract.get_poll(runoff_id).unwrap().option_labels(), vec!["Ann", "Dan"]);
+}
+
+// Options tied with the Nth option at the cut-off are all carried over
+#[test]
+fn test_runoff_keeps_ties_at_cutoff() {
+    let mut contract = setup_contract();
+    let poll_id = closed_poll(&mut contract, runoff_settings(5001, 2), &[0, 0, 0, 1, 1, 2, 2, 3]);
+
+    contract.finalize_poll(poll_id).unwrap();
+    let runoff_id = contract.get_poll(poll_id).unwrap().runoff_poll.unwrap();
+    assert_eq!(contract.get_poll(runoff_id).unwrap().option_labels(), vec!["Ann", "Ben", "Cat"]);
+}
+
+// Runoffs inherit the parent's voter list
+#[test]
+fn test_runoff_inherits_eligibility() {
+    let mut contract = setup_contract();
+    let voters: BTreeSet<String> = (0..4).map(|i| format!("wallet{}", i)).collect();
+
+    let settings = PollSettings {
+        eligible_voters: Some(voters.clone()),
+        ..runoff_settings(6000, 2)
+    };
+    let poll_id = closed_poll(&mut contract, settings, &[0, 1, 2, 0]);
+    contract.finalize_poll(poll_id).unwrap();
+
+    let runoff_id = contract.get_poll(poll_id).unwrap().runoff_poll.unwrap();
+    assert_eq!(contract.get_poll(runoff_id).unwrap().settings.eligible_voters, Some(voters));
+
+    contract.vote(runoff_id, "wallet1".to_string(), 0).unwrap();
+    let result = contract.vote(runoff_id, "outsider".to_string(), 0);
+    assert!(matches!(result, Err(ContractError::NotEligible)));
+}
+
+// Out-of-range runoff settings are rejected
+#[test]
+fn test_invalid_runoff_config() {
+    let mut contract = setup_contract();
+
+    for (settings, expect_time_error) in [
+        (runoff_settings(0, 2), false),
+        (runoff_settings(10_001, 2), false),
+        (runoff_settings(5001, 1), false),
+        (PollSettings { runoff: Some(RunoffConfig { majority_bps: 5001, top_n: 2, duration: 0 }), ..PollSettings::default() }, true),
+    ] {
+        let result = contract.create_poll_with_settings(
+            "creator".to_string(),
+            "Bad".to_string(),
+            "Bad runoff".to_string(),
+            vec!["A".to_string(), "B".to_string(), "C".to_string()],
+            0,
+            u64::MAX,
+            settings,
+        );
+        if expect_time_error {
+            assert!(matches!(result, Err(ContractError::InvalidTimeRange)));
+        } else {
+            assert!(matches!(result, Err(ContractError::InvalidSettings)));
+        }
+    }
+}
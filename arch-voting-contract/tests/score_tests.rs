@@ -0,0 +1,94 @@
+// Tests for score (range) voting polls
+use arch_voting_contract::{
+    ContractError, PollMethod, PollOutcome, PollSettings, RunoffConfig, ScoreTally, VoteChoice, VotingContract,
+};
+
+mod common;
+use common::{open_method_poll, open_poll, setup_contract};
+
+fn create_score_poll(contract: &mut VotingContract, min: u32, max: u32) -> Result<u64, ContractError> {
+    open_method_poll(contract, vec!["Design A", "Design B", "Design C"], PollMethod::Score { min, max })
+}
+
+// Sums, averages and unscored counts are tracked per option
+#[test]
+fn test_score_tally() {
+    let mut contract = setup_contract();
+    let poll_id = create_score_poll(&mut contract, 0, 5).unwrap();
+
+    contract.vote_scores(poll_id, "alice".to_string(), vec![Some(5), Some(3), None]).unwrap();
+    contract.vote_scores(poll_id, "bob".to_string(), vec![Some(2), Some(4), Some(0)]).unwrap();
+    contract.vote_scores(poll_id, "carol".to_string(), vec![Some(4), None, None]).unwrap();
+
+    let results = contract.get_results(poll_id).unwrap();
+    assert_eq!(results.total_votes, 3);
+    assert_eq!(results.scores[&0], ScoreTally { sum: 11, scored: 3, unscored: 0, average_milli: 3666 });
+    assert_eq!(results.scores[&1], ScoreTally { sum: 7, scored: 2, unscored: 1, average_milli: 3500 });
+    assert_eq!(results.scores[&2], ScoreTally { sum: 0, scored: 1, unscored: 2, average_milli: 0 });
+    assert_eq!(results.counts[&0], 11);
+
+    // Ordered results rank by score sum and share out the total score
+    let ordered = contract.get_ordered_results(poll_id).unwrap();
+    assert_eq!(ordered.ranking, vec![0, 1, 2]);
+    let shares: Vec<u32> = ordered.options.iter().map(|o| o.share_bps).collect();
+    assert_eq!(shares, vec![6111, 3889, 0]);
+
+    contract.close_poll(poll_id, "creator".to_string()).unwrap();
+    assert_eq!(contract.get_winner(poll_id).unwrap(), PollOutcome::Winner { option_index: 0, tie_broken: false });
+
+    // Score ballots are committed to and provable
+    let proof = contract.prove_vote(poll_id, "bob").unwrap();
+    assert_eq!(proof.choice, VoteChoice::Scores(vec![Some(2), Some(4), Some(0)]));
+    assert!(proof.verify(&contract.get_state_root()));
+}
+
+// Ballots must match the poll's method, option count and range
+#[test]
+fn test_score_validation() {
+    let mut contract = setup_contract();
+
+    assert!(matches!(create_score_poll(&mut contract, 5, 5), Err(ContractError::InvalidSettings)));
+
+    // A score sum is not a share of voters, so there is no majority runoff
+    let result = open_poll(
+        &mut contract,
+        vec!["Design A", "Design B"],
+        PollSettings {
+            method: PollMethod::Score { min: 0, max: 5 },
+            runoff: Some(RunoffConfig { majority_bps: 5000, top_n: 2, duration: 100 }),
+            ..PollSettings::default()
+        },
+    );
+    assert!(matches!(result, Err(ContractError::InvalidSettings)));
+
+    let poll_id = create_score_poll(&mut contract, 1, 5).unwrap();
+
+    let result = contract.vote_scores(poll_id, "alice".to_string(), vec![Some(0), Some(3), None]);
+    assert!(matches!(result, Err(ContractError::ScoreOutOfRange)));
+    let result = contract.vote_scores(poll_id, "alice".to_string(), vec![Some(6), Some(3), None]);
+    assert!(matches!(result, Err(ContractError::ScoreOutOfRange)));
+    let result = contract.vote_scores(poll_id, "alice".to_string(), vec![Some(3), Some(3)]);
+    assert!(matches!(result, Err(ContractError::InvalidOption)));
+    let result = contract.vote_scores(poll_id, "alice".to_string(), vec![None, None, None]);
+    assert!(matches!(result, Err(ContractError::InvalidOption)));
+    let result = contract.vote(poll_id, "alice".to_string(), 0);
+    assert!(matches!(result, Err(ContractError::MethodMismatch)));
+
+    // Rejected ballots leave no trace, so the wallet can still vote
+    assert!(!contract.has_voted(poll_id, "alice").unwrap());
+    contract.vote_scores(poll_id, "alice".to_string(), vec![Some(1), Some(5), None]).unwrap();
+    let result = contract.vote_scores(poll_id, "alice".to_string(), vec![Some(1), Some(5), None]);
+    assert!(matches!(result, Err(ContractError::AlreadyVoted)));
+
+    // Plurality polls do not take score ballots
+    let plurality = contract.create_poll(
+        "creator".to_string(),
+        "Plurality".to_string(),
+        "One choice".to_string(),
+        vec!["Yes", "No"],
+        0,
+        u64::MAX,
+    ).unwrap();
+    let result = contract.vote_scores(plurality, "alice".to_string(), vec![Some(1), Some(2)]);
+    assert!(matches!(result, Err(ContractError::MethodMismatch)));
+}
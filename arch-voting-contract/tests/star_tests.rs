@@ -0,0 +1,90 @@
+// Tests for STAR (Score Then Automatic Runoff) polls
+use arch_voting_contract::{
+    ContractError, PollMethod, PollOutcome, PollSettings, RunoffConfig,
+};
+
+mod common;
+use common::{open_method_poll, open_poll, setup_contract};
+
+// The highest total can still lose the runoff to the option more voters prefer
+#[test]
+fn test_star_runoff() {
+    let mut contract = setup_contract();
+    let poll_id = open_method_poll(&mut contract, vec!["A", "B", "C"], PollMethod::Star).unwrap();
+
+    let ballots = [
+        vec![Some(5), Some(0), Some(0)],
+        vec![Some(5), Some(0), Some(0)],
+        vec![Some(0), Some(4), Some(3)],
+        vec![Some(1), Some(3), Some(0)],
+        vec![Some(1), Some(2), Some(5)],
+        vec![None, None, Some(1)],
+    ];
+    for (voter, scores) in ballots.into_iter().enumerate() {
+        contract.vote_scores(poll_id, format!("voter{}", voter), scores).unwrap();
+    }
+
+    // B and C tie for second place; the lower index goes through
+    let results = contract.get_star_results(poll_id).unwrap();
+    assert_eq!(results.totals, vec![12, 9, 9]);
+    assert_eq!(results.finalists, vec![0, 1]);
+    assert_eq!(results.preferred, vec![2, 3]);
+    assert_eq!(results.no_preference, 1);
+    assert_eq!(results.winners, vec![1]);
+
+    contract.close_poll(poll_id, "creator".to_string()).unwrap();
+    assert_eq!(contract.get_winner(poll_id).unwrap(), PollOutcome::Winner { option_index: 1, tie_broken: false });
+}
+
+// A runoff tie goes to the higher total, then to the tie-break policy
+#[test]
+fn test_star_runoff_ties() {
+    let mut contract = setup_contract();
+
+    let poll_id = open_method_poll(&mut contract, vec!["A", "B"], PollMethod::Star).unwrap();
+    contract.vote_scores(poll_id, "alice".to_string(), vec![Some(5), Some(0)]).unwrap();
+    contract.vote_scores(poll_id, "bob".to_string(), vec![Some(0), Some(4)]).unwrap();
+    let results = contract.get_star_results(poll_id).unwrap();
+    assert_eq!(results.preferred, vec![1, 1]);
+    assert_eq!(results.winners, vec![0]);
+
+    let poll_id = open_method_poll(&mut contract, vec!["A", "B"], PollMethod::Star).unwrap();
+    contract.vote_scores(poll_id, "alice".to_string(), vec![Some(5), Some(0)]).unwrap();
+    contract.vote_scores(poll_id, "bob".to_string(), vec![Some(0), Some(5)]).unwrap();
+    assert_eq!(contract.get_star_results(poll_id).unwrap().winners, vec![0, 1]);
+
+    contract.close_poll(poll_id, "creator".to_string()).unwrap();
+    assert_eq!(contract.get_winner(poll_id).unwrap(), PollOutcome::Winner { option_index: 0, tie_broken: true });
+}
+
+// Scores stop at 5, STAR has no majority runoff, and only STAR polls report
+// STAR results
+#[test]
+fn test_star_validation() {
+    let mut contract = setup_contract();
+
+    let result = open_poll(
+        &mut contract,
+        vec!["A", "B"],
+        PollSettings {
+            method: PollMethod::Star,
+            runoff: Some(RunoffConfig { majority_bps: 5000, top_n: 2, duration: 100 }),
+            ..PollSettings::default()
+        },
+    );
+    assert!(matches!(result, Err(ContractError::InvalidSettings)));
+
+    let poll_id = open_method_poll(&mut contract, vec!["A", "B"], PollMethod::Star).unwrap();
+    let result = contract.vote_scores(poll_id, "alice".to_string(), vec![Some(6), Some(0)]);
+    assert!(matches!(result, Err(ContractError::ScoreOutOfRange)));
+    let result = contract.vote(poll_id, "alice".to_string(), 0);
+    assert!(matches!(result, Err(ContractError::MethodMismatch)));
+
+    let score_poll = open_method_poll(&mut contract, vec!["A", "B"], PollMethod::Score { min: 0, max: 5 }).unwrap();
+    let result = contract.get_star_results(score_poll);
+    assert!(matches!(result, Err(ContractError::MethodMismatch)));
+
+    contract.cancel_poll(poll_id, "creator".to_string(), "Duplicate poll".to_string()).unwrap();
+    let result = contract.get_star_results(poll_id);
+    assert!(matches!(result, Err(ContractError::PollCancelled)));
+}
@@ -0,0 +1,120 @@
+// Tests for multi-winner STV polls
+use std::collections::BTreeMap;
+
+use arch_voting_contract::stv::VOTE_SCALE;
+use arch_voting_contract::{
+    ContractError, PollMethod, PollOutcome, PollSettings, RunoffConfig, StvAction, VotingContract,
+};
+
+mod common;
+use common::{cast, open_method_poll, open_poll, setup_contract};
+
+fn create_stv_poll(contract: &mut VotingContract, candidates: usize, seats: u32) -> Result<u64, ContractError> {
+    let candidates = (0..candidates).map(|i| format!("Candidate {}", i)).collect();
+    open_method_poll(contract, candidates, PollMethod::Stv { seats })
+}
+
+// A surplus transfer, two exclusions and a final fill of the last seat
+#[test]
+fn test_stv_rounds() {
+    let mut contract = setup_contract();
+    let poll_id = create_stv_poll(&mut contract, 4, 2).unwrap();
+
+    let mut voter = 0;
+    cast(&mut contract, poll_id, 6, &[0, 1], &mut voter);
+    cast(&mut contract, poll_id, 2, &[2], &mut voter);
+    cast(&mut contract, poll_id, 2, &[3, 1], &mut voter);
+
+    let results = contract.get_stv_results(poll_id).unwrap();
+    assert_eq!(results.quota, 4 * VOTE_SCALE);
+    assert_eq!(results.elected, vec![0, 2]);
+    assert_eq!(results.rounds.len(), 4);
+
+    // A is elected with 6 votes and passes on a third of each ballot
+    let first = &results.rounds[0];
+    assert_eq!(first.tallies, BTreeMap::from([(0, 6 * VOTE_SCALE), (1, 0), (2, 2 * VOTE_SCALE), (3, 2 * VOTE_SCALE)]));
+    assert_eq!(first.action, StvAction::Elected {
+        option_index: 0,
+        surplus: 2 * VOTE_SCALE,
+        transfer_value: 333_333_333,
+    });
+    assert_eq!(first.transfers, BTreeMap::from([(1, 1_999_999_998)]));
+
+    // B falls just short of C and D after rounding and is excluded
+    let second = &results.rounds[1];
+    assert_eq!(second.tallies[&1], 1_999_999_998);
+    assert_eq!(second.action, StvAction::Excluded { option_index: 1 });
+    assert_eq!(second.non_transferable, 1_999_999_998);
+
+    // C and D tie; the higher index is excluded
+    let third = &results.rounds[2];
+    assert_eq!(third.action, StvAction::Excluded { option_index: 3 });
+    assert_eq!(third.exhausted, 1_999_999_998);
+
+    assert_eq!(results.rounds[3].action, StvAction::ElectedRemaining { options: vec![2] });
+
+    contract.close_poll(poll_id, "creator".to_string()).unwrap();
+    assert_eq!(contract.get_winner(poll_id).unwrap(), PollOutcome::Elected { options: vec![0, 2] });
+}
+
+// Five seats from twelve candidates always fills every seat exactly once
+#[test]
+fn test_stv_council() {
+    let mut contract = setup_contract();
+    let poll_id = create_stv_poll(&mut contract, 12, 5).unwrap();
+
+    let mut voter = 0;
+    for i in 0..60u32 {
+        let ranking: Vec<u32> = (0..1 + i % 4).map(|step| (i * 7 + step * 5) % 12).collect();
+        cast(&mut contract, poll_id, 1, &ranking, &mut voter);
+    }
+
+    let results = contract.get_stv_results(poll_id).unwrap();
+    assert_eq!(results.quota, 11 * VOTE_SCALE);
+
+    let mut elected = results.elected.clone();
+    assert_eq!(elected.len(), 5);
+    elected.sort();
+    elected.dedup();
+    assert_eq!(elected.len(), 5);
+
+    // Ballot value is never created by a transfer
+    for round in &results.rounds {
+        let held: u128 = round.tallies.values().sum::<u128>() + round.exhausted;
+        assert!(held <= 60 * VOTE_SCALE);
+    }
+}
+
+// Seats must leave at least one candidate unelected, and STV has no runoff
+#[test]
+fn test_stv_validation() {
+    let mut contract = setup_contract();
+
+    assert!(matches!(create_stv_poll(&mut contract, 3, 0), Err(ContractError::InvalidSettings)));
+    assert!(matches!(create_stv_poll(&mut contract, 3, 3), Err(ContractError::InvalidSettings)));
+
+    let result = open_poll(
+        &mut contract,
+        vec!["A", "B", "C"],
+        PollSettings {
+            method: PollMethod::Stv { seats: 1 },
+            runoff: Some(RunoffConfig { majority_bps: 5000, top_n: 2, duration: 100 }),
+            ..PollSettings::default()
+        },
+    );
+    assert!(matches!(result, Err(ContractError::InvalidSettings)));
+
+    let plurality = contract.create_poll(
+        "creator".to_string(),
+        "Plurality".to_string(),
+        "One choice".to_string(),
+        vec!["Yes", "No"],
+        0,
+        u64::MAX,
+    ).unwrap();
+    assert!(matches!(contract.get_stv_results(plurality), Err(ContractError::MethodMismatch)));
+
+    let poll_id = create_stv_poll(&mut contract, 3, 1).unwrap();
+    contract.cancel_poll(poll_id, "creator".to_string(), "Duplicate poll".to_string()).unwrap();
+    assert!(matches!(contract.get_stv_results(poll_id), Err(ContractError::PollCancelled)));
+}
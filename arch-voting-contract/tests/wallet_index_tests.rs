@@ -0,0 +1,88 @@
+// Tests for the per-wallet voting history index
+use arch_voting_contract::{PageRequest, PollSettings, VoteChoice, VotingContract, WalletVote};
+
+mod common;
+use common::{open_poll, setup_contract};
+
+fn with_polls(poll_count: u64) -> VotingContract {
+    let mut contract = setup_contract();
+    for _ in 0..poll_count {
+        open_poll(&mut contract, vec!["A", "B", "C"], PollSettings::default()).unwrap();
+    }
+
+    contract
+}
+
+// Collect a wallet's full history by walking every page
+fn full_history(contract: &VotingContract, wallet: &str, limit: usize) -> Vec<WalletVote> {
+    let mut page = PageRequest { after: None, limit };
+    let mut history = Vec::new();
+    loop {
+        let result = contract.get_votes_by_wallet(wallet, &page);
+        history.extend(result.votes);
+        match result.next_cursor {
+            Some(cursor) => page.after = Some(cursor),
+            None => return history,
+        }
+    }
+}
+
+// The index lists a wallet's votes in poll order, across pages
+#[test]
+fn test_votes_by_wallet() {
+    let mut contract = with_polls(5);
+
+    contract.vote(4, "alice".to_string(), 2).unwrap();
+    contract.vote(1, "alice".to_string(), 0).unwrap();
+    contract.vote(3, "alice".to_string(), 1).unwrap();
+    contract.vote(3, "bob".to_string(), 2).unwrap();
+
+    let page = contract.get_votes_by_wallet("alice", &PageRequest { after: None, limit: 2 });
+    assert_eq!(page.votes, vec![
+        WalletVote { poll_id: 1, choice: VoteChoice::Single(0) },
+        WalletVote { poll_id: 3, choice: VoteChoice::Single(1) },
+    ]);
+    assert_eq!(page.next_cursor, Some(3));
+
+    let page = contract.get_votes_by_wallet("alice", &PageRequest { after: Some(3), limit: 2 });
+    assert_eq!(page.votes, vec![WalletVote { poll_id: 4, choice: VoteChoice::Single(2) }]);
+    assert_eq!(page.next_cursor, None);
+
+    let page = contract.get_votes_by_wallet("nobody", &PageRequest::default());
+    assert!(page.votes.is_empty());
+
+    // Rejected votes do not reach the index
+    assert!(contract.vote(3, "bob".to_string(), 0).is_err());
+    assert_eq!(full_history(&contract, "bob", 10), vec![WalletVote { poll_id: 3, choice: VoteChoice::Single(2) }]);
+}
+
+// The index stays consistent with the per-poll votes map
+#[test]
+// `u64::is_multiple_of` needs Rust 1.87
+#[allow(clippy::manual_is_multiple_of)]
+fn test_index_consistent_with_votes() {
+    let mut contract = with_polls(6);
+    let wallets = ["w0", "w1", "w2", "w3", "w4", "w5", "w6"];
+
+    for (i, wallet) in wallets.iter().enumerate() {
+        for poll_id in 0..6u64 {
+            if (i as u64 + poll_id) % 3 != 0 {
+                contract.vote(poll_id, wallet.to_string(), ((i as u64 * poll_id) % 3) as u32).unwrap();
+            }
+        }
+    }
+
+    // Rebuild every wallet's history from the per-poll records
+    for wallet in wallets {
+        let mut expected = Vec::new();
+        for poll_id in 0..6u64 {
+            let votes = contract.get_poll_votes(poll_id, "owner").unwrap();
+            if let Some((_, choice)) = votes.iter().find(|(w, _)| w == wallet) {
+                expected.push(WalletVote { poll_id, choice: choice.clone() });
+            }
+        }
+
+        assert_eq!(full_history(&contract, wallet, 1), expected);
+        assert_eq!(full_history(&contract, wallet, 100), expected);
+    }
+}
@@ -0,0 +1,128 @@
+// Tests for winner determination and tie-breaking
+use arch_voting_contract::{
+    ContractError, PollOutcome, PollSettings, TieBreakPolicy, VotingContract,
+};
+
+mod common;
+use common::setup_contract;
+
+// Create a poll, cast the given votes in order and close it
+fn closed_poll(contract: &mut VotingContract, tie_break: TieBreakPolicy, votes: &[u32]) -> u64 {
+    let poll_id = contract.create_poll_with_settings(
+        "creator".to_string(),
+        "Winner Poll".to_string(),
+        "Poll used for winner tests".to_string(),
+        vec!["A".to_string(), "B".to_string(), "C".to_string()],
+        0,
+        u64::MAX,
+        PollSettings { tie_break, ..PollSettings::default() },
+    ).unwrap();
+
+    for (i, option) in votes.iter().enumerate() {
+        contract.vote(poll_id, format!("wallet{}", i), *option).unwrap();
+    }
+    contract.close_poll(poll_id, "creator".to_string()).unwrap();
+
+    poll_id
+}
+
+// A clear winner needs no tie-break
+#[test]
+fn test_clear_winner() {
+    let mut contract = setup_contract();
+    let poll_id = contract.create_poll(
+        "creator".to_string(),
+        "Open Poll".to_string(),
+        "Still open".to_string(),
+        vec!["Yes".to_string(), "No".to_string()],
+        0,
+        u64::MAX,
+    ).unwrap();
+    contract.vote(poll_id, "wallet".to_string(), 1).unwrap();
+
+    // No winner while voting is open
+    assert!(matches!(contract.get_winner(poll_id), Err(ContractError::PollNotEnded)));
+
+    contract.close_poll(poll_id, "creator".to_string()).unwrap();
+    assert_eq!(contract.get_winner(poll_id).unwrap(), PollOutcome::Winner { option_index: 1, tie_broken: false });
+
+    let empty = closed_poll(&mut contract, TieBreakPolicy::LowestIndex, &[]);
+    assert_eq!(contract.get_winner(empty).unwrap(), PollOutcome::NoVotes);
+}
+
+// Lowest index and earliest vote policies pick different winners
+#[test]
+fn test_tie_break_policies() {
+    let mut contract = setup_contract();
+
+    // C reaches two votes (second ballot) before B does (fourth ballot)
+    let votes = [2, 2, 1, 1];
+
+    let poll_id = closed_poll(&mut contract, TieBreakPolicy::LowestIndex, &votes);
+    assert_eq!(contract.get_winner(poll_id).unwrap(), PollOutcome::Winner { option_index: 1, tie_broken: true });
+
+    let poll_id = closed_poll(&mut contract, TieBreakPolicy::EarliestVote, &votes);
+    assert_eq!(contract.get_winner(poll_id).unwrap(), PollOutcome::Winner { option_index: 2, tie_broken: true });
+}
+
+// Seeded draws are made at finalization, are deterministic and only pick
+// tied options
+#[test]
+fn test_seeded_draw() {
+    let mut contract = setup_contract();
+    let mut winners = Vec::new();
+
+    for seed_byte in 0..8u8 {
+        let policy = TieBreakPolicy::SeededDraw { seed: [seed_byte; 32] };
+        let poll_id = closed_poll(&mut contract, policy, &[0, 2]);
+
+        // Nothing is drawn until the votes are final
+        assert_eq!(contract.get_winner(poll_id).unwrap(), PollOutcome::Tie { options: vec![0, 2] });
+        contract.finalize_poll(poll_id).unwrap();
+
+        let outcome = contract.get_winner(poll_id).unwrap();
+        assert_eq!(outcome, contract.get_winner(poll_id).unwrap());
+        match outcome {
+            PollOutcome::Winner { option_index, tie_broken: true } => winners.push(option_index),
+            other => panic!("unexpected outcome {:?}", other),
+        }
+    }
+
+    assert!(winners.iter().all(|w| *w == 0 || *w == 2));
+    assert!(winners.contains(&0) && winners.contains(&2));
+}
+
+// A runoff policy reports the tie and finalization opens the runoff
+#[test]
+fn test_runoff_on_tie() {
+    let mut contract = setup_contract();
+    let poll_id = closed_poll(&mut contract, TieBreakPolicy::Runoff { duration: 3600 }, &[0, 2, 1, 2, 0]);
+
+    assert_eq!(contract.get_winner(poll_id).unwrap(), PollOutcome::Tie { options: vec![0, 2] });
+
+    contract.finalize_poll(poll_id).unwrap();
+    let runoff_id = contract.get_poll(poll_id).unwrap().runoff_poll.unwrap();
+
+    let runoff = contract.get_poll(runoff_id).unwrap();
+    assert_eq!(runoff.option_labels(), vec!["A", "C"]);
+    assert_eq!(runoff.parent_poll, Some(poll_id));
+    assert_eq!(runoff.end_time - runoff.start_time, 3600);
+    assert!(runoff.active);
+
+    contract.vote(runoff_id, "wallet0".to_string(), 1).unwrap();
+
+    // Zero-length runoffs are rejected up front
+    let result = contract.create_poll_with_settings(
+        "creator".to_string(),
+        "Bad Poll".to_string(),
+        "Invalid runoff".to_string(),
+        vec!["A".to_string(), "B".to_string()],
+        0,
+        u64::MAX,
+        PollSettings {
+            tie_break: TieBreakPolicy::Runoff { duration: 0 },
+            ..PollSettings::default()
+        },
+    );
+    assert!(matches!(result, Err(ContractError::InvalidTimeRange)));
+}